@@ -64,10 +64,14 @@ def emit_enum_entries(display, file):
         print(f"    {key},", file=file)
 
 
-def print_display_entries(display, file):
+def print_as_str_entries(display, file):
     for [key, doc_comment, deprecated, alternatives, aliases] in display:
-        print("            {0} => f.write_str(\"{0}\"),".format(
-            key), file=file)
+        print("            {0} => \"{0}\",".format(key), file=file)
+
+
+def print_all_entries(display, file):
+    for [key, doc_comment, deprecated, alternatives, aliases] in display:
+        print("            {0},".format(key), file=file)
 
 
 def print_from_str_entries(display, file):
@@ -78,6 +82,14 @@ def print_from_str_entries(display, file):
         print(" => Ok({0}),".format(key), file=file)
 
 
+def print_from_str_const_entries(display, file):
+    for [key, doc_comment, deprecated, alternatives, aliases] in display:
+        print("        if eq(s, \"{0}\")".format(key), file=file, end='')
+        for alternative in alternatives:
+            print(" || eq(s, \"{0}\")".format(alternative), file=file, end='')
+        print(" {{ return Some({0}); }}".format(key), file=file)
+
+
 def add_comment_to(display, key, comment):
     for (i, [found_key, doc_comment, deprecated, alternatives, aliases]) in enumerate(display):
         if found_key != key:
@@ -109,6 +121,7 @@ def convert_key(text, file):
 #![allow(clippy::doc_markdown)]
 #![allow(deprecated)]
 
+use alloc::boxed::Box;
 use core::fmt::{self, Display};
 use core::str::FromStr;
 #[cfg(not(feature = "std"))]
@@ -122,6 +135,7 @@ use std::error::Error;
 /// <https://w3c.github.io/uievents-key/>
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub enum NamedKey {""", file=file)
     display = parse(text)
@@ -145,14 +159,30 @@ pub enum NamedKey {""", file=file)
 
     print("""
 
-impl Display for NamedKey {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+impl NamedKey {
+    /// The name of the key, as used by the [`Display`] implementation.
+    pub const fn as_str(&self) -> &'static str {
         use self::NamedKey::*;
         match *self {""", file=file)
-    print_display_entries(display, file)
+    print_as_str_entries(display, file)
     print("""
         }
     }
+
+    /// All named keys, in the order they are declared.
+    pub const fn all() -> &'static [NamedKey] {
+        use self::NamedKey::*;
+        &[""", file=file)
+    print_all_entries(display, file)
+    print("""
+        ]
+    }
+}
+
+impl Display for NamedKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 impl FromStr for NamedKey {
@@ -163,18 +193,27 @@ impl FromStr for NamedKey {
         match s {""", file=file)
     print_from_str_entries(display, file)
     print("""
-            _ => Err(UnrecognizedNamedKeyError),
+            _ => Err(UnrecognizedNamedKeyError { input: s.into() }),
         }
     }
 }
 
 /// Parse from string error, returned when string does not match to any [`NamedKey`] variant.
 #[derive(Clone, Debug)]
-pub struct UnrecognizedNamedKeyError;
+pub struct UnrecognizedNamedKeyError {
+    input: Box<str>,
+}
+
+impl UnrecognizedNamedKeyError {
+    /// The string that failed to parse.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
 
 impl fmt::Display for UnrecognizedNamedKeyError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Unrecognized key")
+        write!(f, "Unrecognized key: {:?}", self.input)
     }
 }
 
@@ -188,6 +227,7 @@ def convert_code(text, file):
 #![allow(clippy::doc_markdown)]
 #![allow(deprecated)]
 
+use alloc::boxed::Box;
 use core::fmt::{self, Display};
 use core::str::FromStr;
 #[cfg(not(feature = "std"))]
@@ -205,6 +245,7 @@ use std::error::Error;
 /// <https://w3c.github.io/uievents-code/>
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub enum Code {""", file=file)
     display = parse(text)
@@ -275,14 +316,32 @@ pub enum Code {""", file=file)
 
     print("""
 
-impl Display for Code {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+impl Code {
+    /// The name of the code, as used by the [`Display`] implementation.
+    ///
+    /// All code names consist only of ASCII characters.
+    pub const fn as_str(&self) -> &'static str {
         use self::Code::*;
         match *self {""", file=file)
-    print_display_entries(display, file)
+    print_as_str_entries(display, file)
     print("""
         }
     }
+
+    /// All codes, in the order they are declared.
+    pub const fn all() -> &'static [Code] {
+        use self::Code::*;
+        &[""", file=file)
+    print_all_entries(display, file)
+    print("""
+        ]
+    }
+}
+
+impl Display for Code {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 impl FromStr for Code {
@@ -293,18 +352,56 @@ impl FromStr for Code {
         match s {""", file=file)
     print_from_str_entries(display, file)
     print("""
-            _ => Err(UnrecognizedCodeError),
+            _ => Err(UnrecognizedCodeError { input: s.into() }),
         }
     }
 }
 
+impl Code {
+    /// Parse a code from its string representation in a const context.
+    ///
+    /// Accepts the same strings as the [`FromStr`] implementation but returns
+    /// `None` for unrecognized codes. See also the [`code!`](crate::code!) macro.
+    pub const fn from_str_const(s: &str) -> Option<Code> {
+        use crate::Code::*;
+
+        const fn eq(a: &str, b: &str) -> bool {
+            let (a, b) = (a.as_bytes(), b.as_bytes());
+            if a.len() != b.len() {
+                return false;
+            }
+            let mut i = 0;
+            while i < a.len() {
+                if a[i] != b[i] {
+                    return false;
+                }
+                i += 1;
+            }
+            true
+        }
+""", file=file)
+    print_from_str_const_entries(display, file)
+    print("""
+        None
+    }
+}
+
 /// Parse from string error, returned when string does not match to any [`Code`] variant.
 #[derive(Clone, Debug)]
-pub struct UnrecognizedCodeError;
+pub struct UnrecognizedCodeError {
+    input: Box<str>,
+}
+
+impl UnrecognizedCodeError {
+    /// The string that failed to parse.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
 
 impl fmt::Display for UnrecognizedCodeError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Unrecognized code")
+        write!(f, "Unrecognized code: {:?}", self.input)
     }
 }
 
@@ -4,6 +4,7 @@
 #![allow(clippy::doc_markdown)]
 #![allow(deprecated)]
 
+use alloc::boxed::Box;
 use core::fmt::{self, Display};
 use core::str::FromStr;
 #[cfg(not(feature = "std"))]
@@ -21,6 +22,7 @@ use std::error::Error;
 /// <https://w3c.github.io/uievents-code/>
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub enum Code {
     /// <kbd>`~</kbd> on a US keyboard. This is the <kbd>半角/全角/漢字</kbd> (<span class="unicode">hankaku/zenkaku/kanji</span>) key on Japanese keyboards
@@ -488,229 +490,462 @@ pub enum Code {
 }
 
 
-impl Display for Code {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+impl Code {
+    /// The name of the code, as used by the [`Display`] implementation.
+    ///
+    /// All code names consist only of ASCII characters.
+    pub const fn as_str(&self) -> &'static str {
         use self::Code::*;
         match *self {
-            Backquote => f.write_str("Backquote"),
-            Backslash => f.write_str("Backslash"),
-            BracketLeft => f.write_str("BracketLeft"),
-            BracketRight => f.write_str("BracketRight"),
-            Comma => f.write_str("Comma"),
-            Digit0 => f.write_str("Digit0"),
-            Digit1 => f.write_str("Digit1"),
-            Digit2 => f.write_str("Digit2"),
-            Digit3 => f.write_str("Digit3"),
-            Digit4 => f.write_str("Digit4"),
-            Digit5 => f.write_str("Digit5"),
-            Digit6 => f.write_str("Digit6"),
-            Digit7 => f.write_str("Digit7"),
-            Digit8 => f.write_str("Digit8"),
-            Digit9 => f.write_str("Digit9"),
-            Equal => f.write_str("Equal"),
-            IntlBackslash => f.write_str("IntlBackslash"),
-            IntlRo => f.write_str("IntlRo"),
-            IntlYen => f.write_str("IntlYen"),
-            KeyA => f.write_str("KeyA"),
-            KeyB => f.write_str("KeyB"),
-            KeyC => f.write_str("KeyC"),
-            KeyD => f.write_str("KeyD"),
-            KeyE => f.write_str("KeyE"),
-            KeyF => f.write_str("KeyF"),
-            KeyG => f.write_str("KeyG"),
-            KeyH => f.write_str("KeyH"),
-            KeyI => f.write_str("KeyI"),
-            KeyJ => f.write_str("KeyJ"),
-            KeyK => f.write_str("KeyK"),
-            KeyL => f.write_str("KeyL"),
-            KeyM => f.write_str("KeyM"),
-            KeyN => f.write_str("KeyN"),
-            KeyO => f.write_str("KeyO"),
-            KeyP => f.write_str("KeyP"),
-            KeyQ => f.write_str("KeyQ"),
-            KeyR => f.write_str("KeyR"),
-            KeyS => f.write_str("KeyS"),
-            KeyT => f.write_str("KeyT"),
-            KeyU => f.write_str("KeyU"),
-            KeyV => f.write_str("KeyV"),
-            KeyW => f.write_str("KeyW"),
-            KeyX => f.write_str("KeyX"),
-            KeyY => f.write_str("KeyY"),
-            KeyZ => f.write_str("KeyZ"),
-            Minus => f.write_str("Minus"),
-            Period => f.write_str("Period"),
-            Quote => f.write_str("Quote"),
-            Semicolon => f.write_str("Semicolon"),
-            Slash => f.write_str("Slash"),
-            AltLeft => f.write_str("AltLeft"),
-            AltRight => f.write_str("AltRight"),
-            Backspace => f.write_str("Backspace"),
-            CapsLock => f.write_str("CapsLock"),
-            ContextMenu => f.write_str("ContextMenu"),
-            ControlLeft => f.write_str("ControlLeft"),
-            ControlRight => f.write_str("ControlRight"),
-            Enter => f.write_str("Enter"),
-            MetaLeft => f.write_str("MetaLeft"),
-            MetaRight => f.write_str("MetaRight"),
-            ShiftLeft => f.write_str("ShiftLeft"),
-            ShiftRight => f.write_str("ShiftRight"),
-            Space => f.write_str("Space"),
-            Tab => f.write_str("Tab"),
-            Convert => f.write_str("Convert"),
-            KanaMode => f.write_str("KanaMode"),
-            Lang1 => f.write_str("Lang1"),
-            Lang2 => f.write_str("Lang2"),
-            Lang3 => f.write_str("Lang3"),
-            Lang4 => f.write_str("Lang4"),
-            Lang5 => f.write_str("Lang5"),
-            NonConvert => f.write_str("NonConvert"),
-            Delete => f.write_str("Delete"),
-            End => f.write_str("End"),
-            Help => f.write_str("Help"),
-            Home => f.write_str("Home"),
-            Insert => f.write_str("Insert"),
-            PageDown => f.write_str("PageDown"),
-            PageUp => f.write_str("PageUp"),
-            ArrowDown => f.write_str("ArrowDown"),
-            ArrowLeft => f.write_str("ArrowLeft"),
-            ArrowRight => f.write_str("ArrowRight"),
-            ArrowUp => f.write_str("ArrowUp"),
-            NumLock => f.write_str("NumLock"),
-            Numpad0 => f.write_str("Numpad0"),
-            Numpad1 => f.write_str("Numpad1"),
-            Numpad2 => f.write_str("Numpad2"),
-            Numpad3 => f.write_str("Numpad3"),
-            Numpad4 => f.write_str("Numpad4"),
-            Numpad5 => f.write_str("Numpad5"),
-            Numpad6 => f.write_str("Numpad6"),
-            Numpad7 => f.write_str("Numpad7"),
-            Numpad8 => f.write_str("Numpad8"),
-            Numpad9 => f.write_str("Numpad9"),
-            NumpadAdd => f.write_str("NumpadAdd"),
-            NumpadBackspace => f.write_str("NumpadBackspace"),
-            NumpadClear => f.write_str("NumpadClear"),
-            NumpadClearEntry => f.write_str("NumpadClearEntry"),
-            NumpadComma => f.write_str("NumpadComma"),
-            NumpadDecimal => f.write_str("NumpadDecimal"),
-            NumpadDivide => f.write_str("NumpadDivide"),
-            NumpadEnter => f.write_str("NumpadEnter"),
-            NumpadEqual => f.write_str("NumpadEqual"),
-            NumpadHash => f.write_str("NumpadHash"),
-            NumpadMemoryAdd => f.write_str("NumpadMemoryAdd"),
-            NumpadMemoryClear => f.write_str("NumpadMemoryClear"),
-            NumpadMemoryRecall => f.write_str("NumpadMemoryRecall"),
-            NumpadMemoryStore => f.write_str("NumpadMemoryStore"),
-            NumpadMemorySubtract => f.write_str("NumpadMemorySubtract"),
-            NumpadMultiply => f.write_str("NumpadMultiply"),
-            NumpadParenLeft => f.write_str("NumpadParenLeft"),
-            NumpadParenRight => f.write_str("NumpadParenRight"),
-            NumpadStar => f.write_str("NumpadStar"),
-            NumpadSubtract => f.write_str("NumpadSubtract"),
-            Escape => f.write_str("Escape"),
-            Fn => f.write_str("Fn"),
-            FnLock => f.write_str("FnLock"),
-            PrintScreen => f.write_str("PrintScreen"),
-            ScrollLock => f.write_str("ScrollLock"),
-            Pause => f.write_str("Pause"),
-            BrowserBack => f.write_str("BrowserBack"),
-            BrowserFavorites => f.write_str("BrowserFavorites"),
-            BrowserForward => f.write_str("BrowserForward"),
-            BrowserHome => f.write_str("BrowserHome"),
-            BrowserRefresh => f.write_str("BrowserRefresh"),
-            BrowserSearch => f.write_str("BrowserSearch"),
-            BrowserStop => f.write_str("BrowserStop"),
-            Eject => f.write_str("Eject"),
-            LaunchApp1 => f.write_str("LaunchApp1"),
-            LaunchApp2 => f.write_str("LaunchApp2"),
-            LaunchMail => f.write_str("LaunchMail"),
-            MediaPlayPause => f.write_str("MediaPlayPause"),
-            MediaSelect => f.write_str("MediaSelect"),
-            MediaStop => f.write_str("MediaStop"),
-            MediaTrackNext => f.write_str("MediaTrackNext"),
-            MediaTrackPrevious => f.write_str("MediaTrackPrevious"),
-            Power => f.write_str("Power"),
-            Sleep => f.write_str("Sleep"),
-            AudioVolumeDown => f.write_str("AudioVolumeDown"),
-            AudioVolumeMute => f.write_str("AudioVolumeMute"),
-            AudioVolumeUp => f.write_str("AudioVolumeUp"),
-            WakeUp => f.write_str("WakeUp"),
-            Hyper => f.write_str("Hyper"),
-            Super => f.write_str("Super"),
-            Turbo => f.write_str("Turbo"),
-            Abort => f.write_str("Abort"),
-            Resume => f.write_str("Resume"),
-            Suspend => f.write_str("Suspend"),
-            Again => f.write_str("Again"),
-            Copy => f.write_str("Copy"),
-            Cut => f.write_str("Cut"),
-            Find => f.write_str("Find"),
-            Open => f.write_str("Open"),
-            Paste => f.write_str("Paste"),
-            Props => f.write_str("Props"),
-            Select => f.write_str("Select"),
-            Undo => f.write_str("Undo"),
-            Hiragana => f.write_str("Hiragana"),
-            Katakana => f.write_str("Katakana"),
-            Unidentified => f.write_str("Unidentified"),
-            F1 => f.write_str("F1"),
-            F2 => f.write_str("F2"),
-            F3 => f.write_str("F3"),
-            F4 => f.write_str("F4"),
-            F5 => f.write_str("F5"),
-            F6 => f.write_str("F6"),
-            F7 => f.write_str("F7"),
-            F8 => f.write_str("F8"),
-            F9 => f.write_str("F9"),
-            F10 => f.write_str("F10"),
-            F11 => f.write_str("F11"),
-            F12 => f.write_str("F12"),
-            F13 => f.write_str("F13"),
-            F14 => f.write_str("F14"),
-            F15 => f.write_str("F15"),
-            F16 => f.write_str("F16"),
-            F17 => f.write_str("F17"),
-            F18 => f.write_str("F18"),
-            F19 => f.write_str("F19"),
-            F20 => f.write_str("F20"),
-            F21 => f.write_str("F21"),
-            F22 => f.write_str("F22"),
-            F23 => f.write_str("F23"),
-            F24 => f.write_str("F24"),
-            F25 => f.write_str("F25"),
-            F26 => f.write_str("F26"),
-            F27 => f.write_str("F27"),
-            F28 => f.write_str("F28"),
-            F29 => f.write_str("F29"),
-            F30 => f.write_str("F30"),
-            F31 => f.write_str("F31"),
-            F32 => f.write_str("F32"),
-            F33 => f.write_str("F33"),
-            F34 => f.write_str("F34"),
-            F35 => f.write_str("F35"),
-            BrightnessDown => f.write_str("BrightnessDown"),
-            BrightnessUp => f.write_str("BrightnessUp"),
-            DisplayToggleIntExt => f.write_str("DisplayToggleIntExt"),
-            KeyboardLayoutSelect => f.write_str("KeyboardLayoutSelect"),
-            LaunchAssistant => f.write_str("LaunchAssistant"),
-            LaunchControlPanel => f.write_str("LaunchControlPanel"),
-            LaunchScreenSaver => f.write_str("LaunchScreenSaver"),
-            MailForward => f.write_str("MailForward"),
-            MailReply => f.write_str("MailReply"),
-            MailSend => f.write_str("MailSend"),
-            MediaFastForward => f.write_str("MediaFastForward"),
-            MediaPause => f.write_str("MediaPause"),
-            MediaPlay => f.write_str("MediaPlay"),
-            MediaRecord => f.write_str("MediaRecord"),
-            MediaRewind => f.write_str("MediaRewind"),
-            MicrophoneMuteToggle => f.write_str("MicrophoneMuteToggle"),
-            PrivacyScreenToggle => f.write_str("PrivacyScreenToggle"),
-            KeyboardBacklightToggle => f.write_str("KeyboardBacklightToggle"),
-            SelectTask => f.write_str("SelectTask"),
-            ShowAllWindows => f.write_str("ShowAllWindows"),
-            ZoomToggle => f.write_str("ZoomToggle"),
+            Backquote => "Backquote",
+            Backslash => "Backslash",
+            BracketLeft => "BracketLeft",
+            BracketRight => "BracketRight",
+            Comma => "Comma",
+            Digit0 => "Digit0",
+            Digit1 => "Digit1",
+            Digit2 => "Digit2",
+            Digit3 => "Digit3",
+            Digit4 => "Digit4",
+            Digit5 => "Digit5",
+            Digit6 => "Digit6",
+            Digit7 => "Digit7",
+            Digit8 => "Digit8",
+            Digit9 => "Digit9",
+            Equal => "Equal",
+            IntlBackslash => "IntlBackslash",
+            IntlRo => "IntlRo",
+            IntlYen => "IntlYen",
+            KeyA => "KeyA",
+            KeyB => "KeyB",
+            KeyC => "KeyC",
+            KeyD => "KeyD",
+            KeyE => "KeyE",
+            KeyF => "KeyF",
+            KeyG => "KeyG",
+            KeyH => "KeyH",
+            KeyI => "KeyI",
+            KeyJ => "KeyJ",
+            KeyK => "KeyK",
+            KeyL => "KeyL",
+            KeyM => "KeyM",
+            KeyN => "KeyN",
+            KeyO => "KeyO",
+            KeyP => "KeyP",
+            KeyQ => "KeyQ",
+            KeyR => "KeyR",
+            KeyS => "KeyS",
+            KeyT => "KeyT",
+            KeyU => "KeyU",
+            KeyV => "KeyV",
+            KeyW => "KeyW",
+            KeyX => "KeyX",
+            KeyY => "KeyY",
+            KeyZ => "KeyZ",
+            Minus => "Minus",
+            Period => "Period",
+            Quote => "Quote",
+            Semicolon => "Semicolon",
+            Slash => "Slash",
+            AltLeft => "AltLeft",
+            AltRight => "AltRight",
+            Backspace => "Backspace",
+            CapsLock => "CapsLock",
+            ContextMenu => "ContextMenu",
+            ControlLeft => "ControlLeft",
+            ControlRight => "ControlRight",
+            Enter => "Enter",
+            MetaLeft => "MetaLeft",
+            MetaRight => "MetaRight",
+            ShiftLeft => "ShiftLeft",
+            ShiftRight => "ShiftRight",
+            Space => "Space",
+            Tab => "Tab",
+            Convert => "Convert",
+            KanaMode => "KanaMode",
+            Lang1 => "Lang1",
+            Lang2 => "Lang2",
+            Lang3 => "Lang3",
+            Lang4 => "Lang4",
+            Lang5 => "Lang5",
+            NonConvert => "NonConvert",
+            Delete => "Delete",
+            End => "End",
+            Help => "Help",
+            Home => "Home",
+            Insert => "Insert",
+            PageDown => "PageDown",
+            PageUp => "PageUp",
+            ArrowDown => "ArrowDown",
+            ArrowLeft => "ArrowLeft",
+            ArrowRight => "ArrowRight",
+            ArrowUp => "ArrowUp",
+            NumLock => "NumLock",
+            Numpad0 => "Numpad0",
+            Numpad1 => "Numpad1",
+            Numpad2 => "Numpad2",
+            Numpad3 => "Numpad3",
+            Numpad4 => "Numpad4",
+            Numpad5 => "Numpad5",
+            Numpad6 => "Numpad6",
+            Numpad7 => "Numpad7",
+            Numpad8 => "Numpad8",
+            Numpad9 => "Numpad9",
+            NumpadAdd => "NumpadAdd",
+            NumpadBackspace => "NumpadBackspace",
+            NumpadClear => "NumpadClear",
+            NumpadClearEntry => "NumpadClearEntry",
+            NumpadComma => "NumpadComma",
+            NumpadDecimal => "NumpadDecimal",
+            NumpadDivide => "NumpadDivide",
+            NumpadEnter => "NumpadEnter",
+            NumpadEqual => "NumpadEqual",
+            NumpadHash => "NumpadHash",
+            NumpadMemoryAdd => "NumpadMemoryAdd",
+            NumpadMemoryClear => "NumpadMemoryClear",
+            NumpadMemoryRecall => "NumpadMemoryRecall",
+            NumpadMemoryStore => "NumpadMemoryStore",
+            NumpadMemorySubtract => "NumpadMemorySubtract",
+            NumpadMultiply => "NumpadMultiply",
+            NumpadParenLeft => "NumpadParenLeft",
+            NumpadParenRight => "NumpadParenRight",
+            NumpadStar => "NumpadStar",
+            NumpadSubtract => "NumpadSubtract",
+            Escape => "Escape",
+            Fn => "Fn",
+            FnLock => "FnLock",
+            PrintScreen => "PrintScreen",
+            ScrollLock => "ScrollLock",
+            Pause => "Pause",
+            BrowserBack => "BrowserBack",
+            BrowserFavorites => "BrowserFavorites",
+            BrowserForward => "BrowserForward",
+            BrowserHome => "BrowserHome",
+            BrowserRefresh => "BrowserRefresh",
+            BrowserSearch => "BrowserSearch",
+            BrowserStop => "BrowserStop",
+            Eject => "Eject",
+            LaunchApp1 => "LaunchApp1",
+            LaunchApp2 => "LaunchApp2",
+            LaunchMail => "LaunchMail",
+            MediaPlayPause => "MediaPlayPause",
+            MediaSelect => "MediaSelect",
+            MediaStop => "MediaStop",
+            MediaTrackNext => "MediaTrackNext",
+            MediaTrackPrevious => "MediaTrackPrevious",
+            Power => "Power",
+            Sleep => "Sleep",
+            AudioVolumeDown => "AudioVolumeDown",
+            AudioVolumeMute => "AudioVolumeMute",
+            AudioVolumeUp => "AudioVolumeUp",
+            WakeUp => "WakeUp",
+            Hyper => "Hyper",
+            Super => "Super",
+            Turbo => "Turbo",
+            Abort => "Abort",
+            Resume => "Resume",
+            Suspend => "Suspend",
+            Again => "Again",
+            Copy => "Copy",
+            Cut => "Cut",
+            Find => "Find",
+            Open => "Open",
+            Paste => "Paste",
+            Props => "Props",
+            Select => "Select",
+            Undo => "Undo",
+            Hiragana => "Hiragana",
+            Katakana => "Katakana",
+            Unidentified => "Unidentified",
+            F1 => "F1",
+            F2 => "F2",
+            F3 => "F3",
+            F4 => "F4",
+            F5 => "F5",
+            F6 => "F6",
+            F7 => "F7",
+            F8 => "F8",
+            F9 => "F9",
+            F10 => "F10",
+            F11 => "F11",
+            F12 => "F12",
+            F13 => "F13",
+            F14 => "F14",
+            F15 => "F15",
+            F16 => "F16",
+            F17 => "F17",
+            F18 => "F18",
+            F19 => "F19",
+            F20 => "F20",
+            F21 => "F21",
+            F22 => "F22",
+            F23 => "F23",
+            F24 => "F24",
+            F25 => "F25",
+            F26 => "F26",
+            F27 => "F27",
+            F28 => "F28",
+            F29 => "F29",
+            F30 => "F30",
+            F31 => "F31",
+            F32 => "F32",
+            F33 => "F33",
+            F34 => "F34",
+            F35 => "F35",
+            BrightnessDown => "BrightnessDown",
+            BrightnessUp => "BrightnessUp",
+            DisplayToggleIntExt => "DisplayToggleIntExt",
+            KeyboardLayoutSelect => "KeyboardLayoutSelect",
+            LaunchAssistant => "LaunchAssistant",
+            LaunchControlPanel => "LaunchControlPanel",
+            LaunchScreenSaver => "LaunchScreenSaver",
+            MailForward => "MailForward",
+            MailReply => "MailReply",
+            MailSend => "MailSend",
+            MediaFastForward => "MediaFastForward",
+            MediaPause => "MediaPause",
+            MediaPlay => "MediaPlay",
+            MediaRecord => "MediaRecord",
+            MediaRewind => "MediaRewind",
+            MicrophoneMuteToggle => "MicrophoneMuteToggle",
+            PrivacyScreenToggle => "PrivacyScreenToggle",
+            KeyboardBacklightToggle => "KeyboardBacklightToggle",
+            SelectTask => "SelectTask",
+            ShowAllWindows => "ShowAllWindows",
+            ZoomToggle => "ZoomToggle",
 
         }
     }
+
+    /// All codes, in the order they are declared.
+    pub const fn all() -> &'static [Code] {
+        use self::Code::*;
+        &[
+            Backquote,
+            Backslash,
+            BracketLeft,
+            BracketRight,
+            Comma,
+            Digit0,
+            Digit1,
+            Digit2,
+            Digit3,
+            Digit4,
+            Digit5,
+            Digit6,
+            Digit7,
+            Digit8,
+            Digit9,
+            Equal,
+            IntlBackslash,
+            IntlRo,
+            IntlYen,
+            KeyA,
+            KeyB,
+            KeyC,
+            KeyD,
+            KeyE,
+            KeyF,
+            KeyG,
+            KeyH,
+            KeyI,
+            KeyJ,
+            KeyK,
+            KeyL,
+            KeyM,
+            KeyN,
+            KeyO,
+            KeyP,
+            KeyQ,
+            KeyR,
+            KeyS,
+            KeyT,
+            KeyU,
+            KeyV,
+            KeyW,
+            KeyX,
+            KeyY,
+            KeyZ,
+            Minus,
+            Period,
+            Quote,
+            Semicolon,
+            Slash,
+            AltLeft,
+            AltRight,
+            Backspace,
+            CapsLock,
+            ContextMenu,
+            ControlLeft,
+            ControlRight,
+            Enter,
+            MetaLeft,
+            MetaRight,
+            ShiftLeft,
+            ShiftRight,
+            Space,
+            Tab,
+            Convert,
+            KanaMode,
+            Lang1,
+            Lang2,
+            Lang3,
+            Lang4,
+            Lang5,
+            NonConvert,
+            Delete,
+            End,
+            Help,
+            Home,
+            Insert,
+            PageDown,
+            PageUp,
+            ArrowDown,
+            ArrowLeft,
+            ArrowRight,
+            ArrowUp,
+            NumLock,
+            Numpad0,
+            Numpad1,
+            Numpad2,
+            Numpad3,
+            Numpad4,
+            Numpad5,
+            Numpad6,
+            Numpad7,
+            Numpad8,
+            Numpad9,
+            NumpadAdd,
+            NumpadBackspace,
+            NumpadClear,
+            NumpadClearEntry,
+            NumpadComma,
+            NumpadDecimal,
+            NumpadDivide,
+            NumpadEnter,
+            NumpadEqual,
+            NumpadHash,
+            NumpadMemoryAdd,
+            NumpadMemoryClear,
+            NumpadMemoryRecall,
+            NumpadMemoryStore,
+            NumpadMemorySubtract,
+            NumpadMultiply,
+            NumpadParenLeft,
+            NumpadParenRight,
+            NumpadStar,
+            NumpadSubtract,
+            Escape,
+            Fn,
+            FnLock,
+            PrintScreen,
+            ScrollLock,
+            Pause,
+            BrowserBack,
+            BrowserFavorites,
+            BrowserForward,
+            BrowserHome,
+            BrowserRefresh,
+            BrowserSearch,
+            BrowserStop,
+            Eject,
+            LaunchApp1,
+            LaunchApp2,
+            LaunchMail,
+            MediaPlayPause,
+            MediaSelect,
+            MediaStop,
+            MediaTrackNext,
+            MediaTrackPrevious,
+            Power,
+            Sleep,
+            AudioVolumeDown,
+            AudioVolumeMute,
+            AudioVolumeUp,
+            WakeUp,
+            Hyper,
+            Super,
+            Turbo,
+            Abort,
+            Resume,
+            Suspend,
+            Again,
+            Copy,
+            Cut,
+            Find,
+            Open,
+            Paste,
+            Props,
+            Select,
+            Undo,
+            Hiragana,
+            Katakana,
+            Unidentified,
+            F1,
+            F2,
+            F3,
+            F4,
+            F5,
+            F6,
+            F7,
+            F8,
+            F9,
+            F10,
+            F11,
+            F12,
+            F13,
+            F14,
+            F15,
+            F16,
+            F17,
+            F18,
+            F19,
+            F20,
+            F21,
+            F22,
+            F23,
+            F24,
+            F25,
+            F26,
+            F27,
+            F28,
+            F29,
+            F30,
+            F31,
+            F32,
+            F33,
+            F34,
+            F35,
+            BrightnessDown,
+            BrightnessUp,
+            DisplayToggleIntExt,
+            KeyboardLayoutSelect,
+            LaunchAssistant,
+            LaunchControlPanel,
+            LaunchScreenSaver,
+            MailForward,
+            MailReply,
+            MailSend,
+            MediaFastForward,
+            MediaPause,
+            MediaPlay,
+            MediaRecord,
+            MediaRewind,
+            MicrophoneMuteToggle,
+            PrivacyScreenToggle,
+            KeyboardBacklightToggle,
+            SelectTask,
+            ShowAllWindows,
+            ZoomToggle,
+
+        ]
+    }
+}
+
+impl Display for Code {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 impl FromStr for Code {
@@ -936,18 +1171,271 @@ impl FromStr for Code {
             "ShowAllWindows" => Ok(ShowAllWindows),
             "ZoomToggle" => Ok(ZoomToggle),
 
-            _ => Err(UnrecognizedCodeError),
+            _ => Err(UnrecognizedCodeError { input: s.into() }),
         }
     }
 }
 
+impl Code {
+    /// Parse a code from its string representation in a const context.
+    ///
+    /// Accepts the same strings as the [`FromStr`] implementation but returns
+    /// `None` for unrecognized codes. See also the [`code!`](crate::code!) macro.
+    pub const fn from_str_const(s: &str) -> Option<Code> {
+        use crate::Code::*;
+
+        const fn eq(a: &str, b: &str) -> bool {
+            let (a, b) = (a.as_bytes(), b.as_bytes());
+            if a.len() != b.len() {
+                return false;
+            }
+            let mut i = 0;
+            while i < a.len() {
+                if a[i] != b[i] {
+                    return false;
+                }
+                i += 1;
+            }
+            true
+        }
+
+        if eq(s, "Backquote") { return Some(Backquote); }
+        if eq(s, "Backslash") { return Some(Backslash); }
+        if eq(s, "BracketLeft") { return Some(BracketLeft); }
+        if eq(s, "BracketRight") { return Some(BracketRight); }
+        if eq(s, "Comma") { return Some(Comma); }
+        if eq(s, "Digit0") { return Some(Digit0); }
+        if eq(s, "Digit1") { return Some(Digit1); }
+        if eq(s, "Digit2") { return Some(Digit2); }
+        if eq(s, "Digit3") { return Some(Digit3); }
+        if eq(s, "Digit4") { return Some(Digit4); }
+        if eq(s, "Digit5") { return Some(Digit5); }
+        if eq(s, "Digit6") { return Some(Digit6); }
+        if eq(s, "Digit7") { return Some(Digit7); }
+        if eq(s, "Digit8") { return Some(Digit8); }
+        if eq(s, "Digit9") { return Some(Digit9); }
+        if eq(s, "Equal") { return Some(Equal); }
+        if eq(s, "IntlBackslash") { return Some(IntlBackslash); }
+        if eq(s, "IntlRo") { return Some(IntlRo); }
+        if eq(s, "IntlYen") { return Some(IntlYen); }
+        if eq(s, "KeyA") { return Some(KeyA); }
+        if eq(s, "KeyB") { return Some(KeyB); }
+        if eq(s, "KeyC") { return Some(KeyC); }
+        if eq(s, "KeyD") { return Some(KeyD); }
+        if eq(s, "KeyE") { return Some(KeyE); }
+        if eq(s, "KeyF") { return Some(KeyF); }
+        if eq(s, "KeyG") { return Some(KeyG); }
+        if eq(s, "KeyH") { return Some(KeyH); }
+        if eq(s, "KeyI") { return Some(KeyI); }
+        if eq(s, "KeyJ") { return Some(KeyJ); }
+        if eq(s, "KeyK") { return Some(KeyK); }
+        if eq(s, "KeyL") { return Some(KeyL); }
+        if eq(s, "KeyM") { return Some(KeyM); }
+        if eq(s, "KeyN") { return Some(KeyN); }
+        if eq(s, "KeyO") { return Some(KeyO); }
+        if eq(s, "KeyP") { return Some(KeyP); }
+        if eq(s, "KeyQ") { return Some(KeyQ); }
+        if eq(s, "KeyR") { return Some(KeyR); }
+        if eq(s, "KeyS") { return Some(KeyS); }
+        if eq(s, "KeyT") { return Some(KeyT); }
+        if eq(s, "KeyU") { return Some(KeyU); }
+        if eq(s, "KeyV") { return Some(KeyV); }
+        if eq(s, "KeyW") { return Some(KeyW); }
+        if eq(s, "KeyX") { return Some(KeyX); }
+        if eq(s, "KeyY") { return Some(KeyY); }
+        if eq(s, "KeyZ") { return Some(KeyZ); }
+        if eq(s, "Minus") { return Some(Minus); }
+        if eq(s, "Period") { return Some(Period); }
+        if eq(s, "Quote") { return Some(Quote); }
+        if eq(s, "Semicolon") { return Some(Semicolon); }
+        if eq(s, "Slash") { return Some(Slash); }
+        if eq(s, "AltLeft") { return Some(AltLeft); }
+        if eq(s, "AltRight") { return Some(AltRight); }
+        if eq(s, "Backspace") { return Some(Backspace); }
+        if eq(s, "CapsLock") { return Some(CapsLock); }
+        if eq(s, "ContextMenu") { return Some(ContextMenu); }
+        if eq(s, "ControlLeft") { return Some(ControlLeft); }
+        if eq(s, "ControlRight") { return Some(ControlRight); }
+        if eq(s, "Enter") { return Some(Enter); }
+        if eq(s, "MetaLeft") || eq(s, "OSLeft") { return Some(MetaLeft); }
+        if eq(s, "MetaRight") || eq(s, "OSRight") { return Some(MetaRight); }
+        if eq(s, "ShiftLeft") { return Some(ShiftLeft); }
+        if eq(s, "ShiftRight") { return Some(ShiftRight); }
+        if eq(s, "Space") { return Some(Space); }
+        if eq(s, "Tab") { return Some(Tab); }
+        if eq(s, "Convert") { return Some(Convert); }
+        if eq(s, "KanaMode") { return Some(KanaMode); }
+        if eq(s, "Lang1") { return Some(Lang1); }
+        if eq(s, "Lang2") { return Some(Lang2); }
+        if eq(s, "Lang3") { return Some(Lang3); }
+        if eq(s, "Lang4") { return Some(Lang4); }
+        if eq(s, "Lang5") { return Some(Lang5); }
+        if eq(s, "NonConvert") { return Some(NonConvert); }
+        if eq(s, "Delete") { return Some(Delete); }
+        if eq(s, "End") { return Some(End); }
+        if eq(s, "Help") { return Some(Help); }
+        if eq(s, "Home") { return Some(Home); }
+        if eq(s, "Insert") { return Some(Insert); }
+        if eq(s, "PageDown") { return Some(PageDown); }
+        if eq(s, "PageUp") { return Some(PageUp); }
+        if eq(s, "ArrowDown") { return Some(ArrowDown); }
+        if eq(s, "ArrowLeft") { return Some(ArrowLeft); }
+        if eq(s, "ArrowRight") { return Some(ArrowRight); }
+        if eq(s, "ArrowUp") { return Some(ArrowUp); }
+        if eq(s, "NumLock") { return Some(NumLock); }
+        if eq(s, "Numpad0") { return Some(Numpad0); }
+        if eq(s, "Numpad1") { return Some(Numpad1); }
+        if eq(s, "Numpad2") { return Some(Numpad2); }
+        if eq(s, "Numpad3") { return Some(Numpad3); }
+        if eq(s, "Numpad4") { return Some(Numpad4); }
+        if eq(s, "Numpad5") { return Some(Numpad5); }
+        if eq(s, "Numpad6") { return Some(Numpad6); }
+        if eq(s, "Numpad7") { return Some(Numpad7); }
+        if eq(s, "Numpad8") { return Some(Numpad8); }
+        if eq(s, "Numpad9") { return Some(Numpad9); }
+        if eq(s, "NumpadAdd") { return Some(NumpadAdd); }
+        if eq(s, "NumpadBackspace") { return Some(NumpadBackspace); }
+        if eq(s, "NumpadClear") { return Some(NumpadClear); }
+        if eq(s, "NumpadClearEntry") { return Some(NumpadClearEntry); }
+        if eq(s, "NumpadComma") { return Some(NumpadComma); }
+        if eq(s, "NumpadDecimal") { return Some(NumpadDecimal); }
+        if eq(s, "NumpadDivide") { return Some(NumpadDivide); }
+        if eq(s, "NumpadEnter") { return Some(NumpadEnter); }
+        if eq(s, "NumpadEqual") { return Some(NumpadEqual); }
+        if eq(s, "NumpadHash") { return Some(NumpadHash); }
+        if eq(s, "NumpadMemoryAdd") { return Some(NumpadMemoryAdd); }
+        if eq(s, "NumpadMemoryClear") { return Some(NumpadMemoryClear); }
+        if eq(s, "NumpadMemoryRecall") { return Some(NumpadMemoryRecall); }
+        if eq(s, "NumpadMemoryStore") { return Some(NumpadMemoryStore); }
+        if eq(s, "NumpadMemorySubtract") { return Some(NumpadMemorySubtract); }
+        if eq(s, "NumpadMultiply") { return Some(NumpadMultiply); }
+        if eq(s, "NumpadParenLeft") { return Some(NumpadParenLeft); }
+        if eq(s, "NumpadParenRight") { return Some(NumpadParenRight); }
+        if eq(s, "NumpadStar") { return Some(NumpadStar); }
+        if eq(s, "NumpadSubtract") { return Some(NumpadSubtract); }
+        if eq(s, "Escape") { return Some(Escape); }
+        if eq(s, "Fn") { return Some(Fn); }
+        if eq(s, "FnLock") { return Some(FnLock); }
+        if eq(s, "PrintScreen") { return Some(PrintScreen); }
+        if eq(s, "ScrollLock") { return Some(ScrollLock); }
+        if eq(s, "Pause") { return Some(Pause); }
+        if eq(s, "BrowserBack") { return Some(BrowserBack); }
+        if eq(s, "BrowserFavorites") { return Some(BrowserFavorites); }
+        if eq(s, "BrowserForward") { return Some(BrowserForward); }
+        if eq(s, "BrowserHome") { return Some(BrowserHome); }
+        if eq(s, "BrowserRefresh") { return Some(BrowserRefresh); }
+        if eq(s, "BrowserSearch") { return Some(BrowserSearch); }
+        if eq(s, "BrowserStop") { return Some(BrowserStop); }
+        if eq(s, "Eject") { return Some(Eject); }
+        if eq(s, "LaunchApp1") { return Some(LaunchApp1); }
+        if eq(s, "LaunchApp2") { return Some(LaunchApp2); }
+        if eq(s, "LaunchMail") { return Some(LaunchMail); }
+        if eq(s, "MediaPlayPause") { return Some(MediaPlayPause); }
+        if eq(s, "MediaSelect") || eq(s, "LaunchMediaPlayer") { return Some(MediaSelect); }
+        if eq(s, "MediaStop") { return Some(MediaStop); }
+        if eq(s, "MediaTrackNext") { return Some(MediaTrackNext); }
+        if eq(s, "MediaTrackPrevious") { return Some(MediaTrackPrevious); }
+        if eq(s, "Power") { return Some(Power); }
+        if eq(s, "Sleep") { return Some(Sleep); }
+        if eq(s, "AudioVolumeDown") || eq(s, "VolumeDown") { return Some(AudioVolumeDown); }
+        if eq(s, "AudioVolumeMute") || eq(s, "VolumeMute") { return Some(AudioVolumeMute); }
+        if eq(s, "AudioVolumeUp") || eq(s, "VolumeUp") { return Some(AudioVolumeUp); }
+        if eq(s, "WakeUp") { return Some(WakeUp); }
+        if eq(s, "Hyper") { return Some(Hyper); }
+        if eq(s, "Super") { return Some(Super); }
+        if eq(s, "Turbo") { return Some(Turbo); }
+        if eq(s, "Abort") { return Some(Abort); }
+        if eq(s, "Resume") { return Some(Resume); }
+        if eq(s, "Suspend") { return Some(Suspend); }
+        if eq(s, "Again") { return Some(Again); }
+        if eq(s, "Copy") { return Some(Copy); }
+        if eq(s, "Cut") { return Some(Cut); }
+        if eq(s, "Find") { return Some(Find); }
+        if eq(s, "Open") { return Some(Open); }
+        if eq(s, "Paste") { return Some(Paste); }
+        if eq(s, "Props") { return Some(Props); }
+        if eq(s, "Select") { return Some(Select); }
+        if eq(s, "Undo") { return Some(Undo); }
+        if eq(s, "Hiragana") { return Some(Hiragana); }
+        if eq(s, "Katakana") { return Some(Katakana); }
+        if eq(s, "Unidentified") { return Some(Unidentified); }
+        if eq(s, "F1") { return Some(F1); }
+        if eq(s, "F2") { return Some(F2); }
+        if eq(s, "F3") { return Some(F3); }
+        if eq(s, "F4") { return Some(F4); }
+        if eq(s, "F5") { return Some(F5); }
+        if eq(s, "F6") { return Some(F6); }
+        if eq(s, "F7") { return Some(F7); }
+        if eq(s, "F8") { return Some(F8); }
+        if eq(s, "F9") { return Some(F9); }
+        if eq(s, "F10") { return Some(F10); }
+        if eq(s, "F11") { return Some(F11); }
+        if eq(s, "F12") { return Some(F12); }
+        if eq(s, "F13") { return Some(F13); }
+        if eq(s, "F14") { return Some(F14); }
+        if eq(s, "F15") { return Some(F15); }
+        if eq(s, "F16") { return Some(F16); }
+        if eq(s, "F17") { return Some(F17); }
+        if eq(s, "F18") { return Some(F18); }
+        if eq(s, "F19") { return Some(F19); }
+        if eq(s, "F20") { return Some(F20); }
+        if eq(s, "F21") { return Some(F21); }
+        if eq(s, "F22") { return Some(F22); }
+        if eq(s, "F23") { return Some(F23); }
+        if eq(s, "F24") { return Some(F24); }
+        if eq(s, "F25") { return Some(F25); }
+        if eq(s, "F26") { return Some(F26); }
+        if eq(s, "F27") { return Some(F27); }
+        if eq(s, "F28") { return Some(F28); }
+        if eq(s, "F29") { return Some(F29); }
+        if eq(s, "F30") { return Some(F30); }
+        if eq(s, "F31") { return Some(F31); }
+        if eq(s, "F32") { return Some(F32); }
+        if eq(s, "F33") { return Some(F33); }
+        if eq(s, "F34") { return Some(F34); }
+        if eq(s, "F35") { return Some(F35); }
+        if eq(s, "BrightnessDown") { return Some(BrightnessDown); }
+        if eq(s, "BrightnessUp") { return Some(BrightnessUp); }
+        if eq(s, "DisplayToggleIntExt") { return Some(DisplayToggleIntExt); }
+        if eq(s, "KeyboardLayoutSelect") { return Some(KeyboardLayoutSelect); }
+        if eq(s, "LaunchAssistant") { return Some(LaunchAssistant); }
+        if eq(s, "LaunchControlPanel") { return Some(LaunchControlPanel); }
+        if eq(s, "LaunchScreenSaver") { return Some(LaunchScreenSaver); }
+        if eq(s, "MailForward") { return Some(MailForward); }
+        if eq(s, "MailReply") { return Some(MailReply); }
+        if eq(s, "MailSend") { return Some(MailSend); }
+        if eq(s, "MediaFastForward") { return Some(MediaFastForward); }
+        if eq(s, "MediaPause") { return Some(MediaPause); }
+        if eq(s, "MediaPlay") { return Some(MediaPlay); }
+        if eq(s, "MediaRecord") { return Some(MediaRecord); }
+        if eq(s, "MediaRewind") { return Some(MediaRewind); }
+        if eq(s, "MicrophoneMuteToggle") { return Some(MicrophoneMuteToggle); }
+        if eq(s, "PrivacyScreenToggle") { return Some(PrivacyScreenToggle); }
+        if eq(s, "KeyboardBacklightToggle") { return Some(KeyboardBacklightToggle); }
+        if eq(s, "SelectTask") { return Some(SelectTask); }
+        if eq(s, "ShowAllWindows") { return Some(ShowAllWindows); }
+        if eq(s, "ZoomToggle") { return Some(ZoomToggle); }
+
+        None
+    }
+}
+
 /// Parse from string error, returned when string does not match to any [`Code`] variant.
 #[derive(Clone, Debug)]
-pub struct UnrecognizedCodeError;
+pub struct UnrecognizedCodeError {
+    input: Box<str>,
+}
+
+impl UnrecognizedCodeError {
+    /// The string that failed to parse.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
 
 impl fmt::Display for UnrecognizedCodeError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Unrecognized code")
+        write!(f, "Unrecognized code: {:?}", self.input)
     }
 }
 
@@ -0,0 +1,917 @@
+use alloc::string::ToString;
+use core::str::FromStr;
+
+use crate::{Code, Key, Location, NamedKey, UnrecognizedCodeError};
+
+impl Code {
+    /// The arrow keys in the order left, up, right, down.
+    pub const ARROWS: [Code; 4] = [
+        Code::ArrowLeft,
+        Code::ArrowUp,
+        Code::ArrowRight,
+        Code::ArrowDown,
+    ];
+
+    /// The function keys in order, from [`Code::F1`] to [`Code::F35`].
+    pub const FUNCTION_KEYS: &'static [Code] = &[
+        Code::F1,
+        Code::F2,
+        Code::F3,
+        Code::F4,
+        Code::F5,
+        Code::F6,
+        Code::F7,
+        Code::F8,
+        Code::F9,
+        Code::F10,
+        Code::F11,
+        Code::F12,
+        Code::F13,
+        Code::F14,
+        Code::F15,
+        Code::F16,
+        Code::F17,
+        Code::F18,
+        Code::F19,
+        Code::F20,
+        Code::F21,
+        Code::F22,
+        Code::F23,
+        Code::F24,
+        Code::F25,
+        Code::F26,
+        Code::F27,
+        Code::F28,
+        Code::F29,
+        Code::F30,
+        Code::F31,
+        Code::F32,
+        Code::F33,
+        Code::F34,
+        Code::F35,
+    ];
+
+    /// The keys on the numeric keypad, see [`Code::is_numpad`].
+    pub const NUMPAD: &'static [Code] = &[
+        Code::Numpad0,
+        Code::Numpad1,
+        Code::Numpad2,
+        Code::Numpad3,
+        Code::Numpad4,
+        Code::Numpad5,
+        Code::Numpad6,
+        Code::Numpad7,
+        Code::Numpad8,
+        Code::Numpad9,
+        Code::NumpadAdd,
+        Code::NumpadBackspace,
+        Code::NumpadClear,
+        Code::NumpadClearEntry,
+        Code::NumpadComma,
+        Code::NumpadDecimal,
+        Code::NumpadDivide,
+        Code::NumpadEnter,
+        Code::NumpadEqual,
+        Code::NumpadHash,
+        Code::NumpadMemoryAdd,
+        Code::NumpadMemoryClear,
+        Code::NumpadMemoryRecall,
+        Code::NumpadMemoryStore,
+        Code::NumpadMemorySubtract,
+        Code::NumpadMultiply,
+        Code::NumpadParenLeft,
+        Code::NumpadParenRight,
+        Code::NumpadStar,
+        Code::NumpadSubtract,
+    ];
+
+    /// The left and right <kbd>Alt</kbd>, <kbd>Control</kbd>, <kbd>Meta</kbd>
+    /// and <kbd>Shift</kbd> keys.
+    pub const MODIFIERS: &'static [Code] = &[
+        Code::AltLeft,
+        Code::AltRight,
+        Code::ControlLeft,
+        Code::ControlRight,
+        Code::MetaLeft,
+        Code::MetaRight,
+        Code::ShiftLeft,
+        Code::ShiftRight,
+    ];
+
+    /// Parse a code, also accepting non-standard names used by other sources.
+    ///
+    /// In addition to all names accepted by [`Code::from_str`] the following
+    /// legacy X11 names for the left and right meta keys are recognized:
+    ///
+    /// | Input                        | Code                |
+    /// |------------------------------|---------------------|
+    /// | `SuperLeft`, `HyperLeft`     | [`Code::MetaLeft`]  |
+    /// | `SuperRight`, `HyperRight`   | [`Code::MetaRight`] |
+    ///
+    /// Numpad operators are also accepted by the name of their symbol:
+    ///
+    /// | Input                        | Code                       |
+    /// |------------------------------|----------------------------|
+    /// | `NumpadPlus`                 | [`Code::NumpadAdd`]        |
+    /// | `NumpadMinus`                | [`Code::NumpadSubtract`]   |
+    /// | `NumpadSlash`                | [`Code::NumpadDivide`]     |
+    ///
+    /// `NumpadStar` is not an alias of [`Code::NumpadMultiply`], the
+    /// specification defines [`Code::NumpadStar`] for the <kbd>*</kbd> key on
+    /// phone keypads.
+    ///
+    /// The names `OSLeft` and `OSRight` are handled by [`Code::from_str`]
+    /// itself. Firefox reported them for the meta keys before version 118,
+    /// when an earlier draft of the specification still used these names.
+    ///
+    /// The vendor prefixes `Moz` and `WebKit` are removed before matching,
+    /// so `MozKeyA` is parsed as [`Code::KeyA`].
+    pub fn from_str_lenient(s: &str) -> Result<Code, UnrecognizedCodeError> {
+        let name = s
+            .strip_prefix("Moz")
+            .or_else(|| s.strip_prefix("WebKit"))
+            .unwrap_or(s);
+        Code::from_str(name).or_else(|_| match name {
+            "SuperLeft" | "HyperLeft" => Ok(Code::MetaLeft),
+            "SuperRight" | "HyperRight" => Ok(Code::MetaRight),
+            "NumpadPlus" => Ok(Code::NumpadAdd),
+            "NumpadMinus" => Ok(Code::NumpadSubtract),
+            "NumpadSlash" => Ok(Code::NumpadDivide),
+            // Report the error for the original input.
+            _ => Code::from_str(s),
+        })
+    }
+
+    /// Recover the code of a named key from its location.
+    ///
+    /// Handles the modifier keys with a left and right variant and the keys
+    /// of the numeric keypad when `NumLock` is off (e.g. <kbd>End</kbd> with
+    /// [`Location::Numpad`] is [`Code::Numpad1`]). Returns `None` if the
+    /// code cannot be determined, including modifier keys without a side.
+    pub fn from_named_key_and_location(key: NamedKey, location: Location) -> Option<Code> {
+        let code = match (key, location) {
+            (NamedKey::Alt, Location::Left) => Code::AltLeft,
+            (NamedKey::Alt, Location::Right) => Code::AltRight,
+            (NamedKey::AltGraph, _) => Code::AltRight,
+            (NamedKey::Control, Location::Left) => Code::ControlLeft,
+            (NamedKey::Control, Location::Right) => Code::ControlRight,
+            (NamedKey::Meta, Location::Left) => Code::MetaLeft,
+            (NamedKey::Meta, Location::Right) => Code::MetaRight,
+            (NamedKey::Shift, Location::Left) => Code::ShiftLeft,
+            (NamedKey::Shift, Location::Right) => Code::ShiftRight,
+            (NamedKey::CapsLock, _) => Code::CapsLock,
+            (NamedKey::NumLock, _) => Code::NumLock,
+            (NamedKey::Insert, Location::Numpad) => Code::Numpad0,
+            (NamedKey::End, Location::Numpad) => Code::Numpad1,
+            (NamedKey::ArrowDown, Location::Numpad) => Code::Numpad2,
+            (NamedKey::PageDown, Location::Numpad) => Code::Numpad3,
+            (NamedKey::ArrowLeft, Location::Numpad) => Code::Numpad4,
+            (NamedKey::Clear, Location::Numpad) => Code::Numpad5,
+            (NamedKey::ArrowRight, Location::Numpad) => Code::Numpad6,
+            (NamedKey::Home, Location::Numpad) => Code::Numpad7,
+            (NamedKey::ArrowUp, Location::Numpad) => Code::Numpad8,
+            (NamedKey::PageUp, Location::Numpad) => Code::Numpad9,
+            (NamedKey::Delete, Location::Numpad) => Code::NumpadDecimal,
+            (NamedKey::Enter, Location::Numpad) => Code::NumpadEnter,
+            (NamedKey::Insert, Location::Standard) => Code::Insert,
+            (NamedKey::End, Location::Standard) => Code::End,
+            (NamedKey::ArrowDown, Location::Standard) => Code::ArrowDown,
+            (NamedKey::PageDown, Location::Standard) => Code::PageDown,
+            (NamedKey::ArrowLeft, Location::Standard) => Code::ArrowLeft,
+            (NamedKey::ArrowRight, Location::Standard) => Code::ArrowRight,
+            (NamedKey::Home, Location::Standard) => Code::Home,
+            (NamedKey::ArrowUp, Location::Standard) => Code::ArrowUp,
+            (NamedKey::PageUp, Location::Standard) => Code::PageUp,
+            (NamedKey::Delete, Location::Standard) => Code::Delete,
+            (NamedKey::Enter, Location::Standard) => Code::Enter,
+            _ => return None,
+        };
+        Some(code)
+    }
+
+    /// Return `true` if the key commonly produces a dead key on some layouts.
+    ///
+    /// The layout is unknown, so this is only a hint. The set is based on the
+    /// dead keys of the US International layout (grave accent, apostrophe and
+    /// the circumflex on [`Code::Digit6`]) and the punctuation keys used for
+    /// accents on common European layouts (e.g. the acute accent on
+    /// [`Code::Equal`] in German):
+    ///
+    /// [`Code::Backquote`], [`Code::Quote`], [`Code::Digit6`], [`Code::Equal`],
+    /// [`Code::BracketLeft`], [`Code::BracketRight`], [`Code::Semicolon`] and
+    /// [`Code::Backslash`].
+    pub fn is_dead_key_candidate(self) -> bool {
+        matches!(
+            self,
+            Code::Backquote
+                | Code::Quote
+                | Code::Digit6
+                | Code::Equal
+                | Code::BracketLeft
+                | Code::BracketRight
+                | Code::Semicolon
+                | Code::Backslash
+        )
+    }
+
+    /// The keys physically adjacent to this key.
+    ///
+    /// Uses the row-staggered US QWERTY layout of the alphanumeric section.
+    /// The keys left and right in the same row come first, followed by the
+    /// touching keys in the row above and below. Keys outside of the digit,
+    /// letter and punctuation rows have no neighbors.
+    ///
+    /// ```rust
+    /// # use keyboard_types::Code;
+    /// assert_eq!(
+    ///     Code::KeyG.neighbors(),
+    ///     [Code::KeyF, Code::KeyH, Code::KeyT, Code::KeyY, Code::KeyV, Code::KeyB]
+    /// );
+    /// ```
+    pub fn neighbors(self) -> &'static [Code] {
+        match self {
+            Code::Backquote => &[Code::Digit1],
+            Code::Digit1 => &[Code::Backquote, Code::Digit2, Code::KeyQ],
+            Code::Digit2 => &[Code::Digit1, Code::Digit3, Code::KeyQ, Code::KeyW],
+            Code::Digit3 => &[Code::Digit2, Code::Digit4, Code::KeyW, Code::KeyE],
+            Code::Digit4 => &[Code::Digit3, Code::Digit5, Code::KeyE, Code::KeyR],
+            Code::Digit5 => &[Code::Digit4, Code::Digit6, Code::KeyR, Code::KeyT],
+            Code::Digit6 => &[Code::Digit5, Code::Digit7, Code::KeyT, Code::KeyY],
+            Code::Digit7 => &[Code::Digit6, Code::Digit8, Code::KeyY, Code::KeyU],
+            Code::Digit8 => &[Code::Digit7, Code::Digit9, Code::KeyU, Code::KeyI],
+            Code::Digit9 => &[Code::Digit8, Code::Digit0, Code::KeyI, Code::KeyO],
+            Code::Digit0 => &[Code::Digit9, Code::Minus, Code::KeyO, Code::KeyP],
+            Code::Minus => &[Code::Digit0, Code::Equal, Code::KeyP, Code::BracketLeft],
+            Code::Equal => &[Code::Minus, Code::BracketLeft, Code::BracketRight],
+            Code::KeyQ => &[Code::KeyW, Code::Digit1, Code::Digit2, Code::KeyA],
+            Code::KeyW => &[
+                Code::KeyQ,
+                Code::KeyE,
+                Code::Digit2,
+                Code::Digit3,
+                Code::KeyA,
+                Code::KeyS,
+            ],
+            Code::KeyE => &[
+                Code::KeyW,
+                Code::KeyR,
+                Code::Digit3,
+                Code::Digit4,
+                Code::KeyS,
+                Code::KeyD,
+            ],
+            Code::KeyR => &[
+                Code::KeyE,
+                Code::KeyT,
+                Code::Digit4,
+                Code::Digit5,
+                Code::KeyD,
+                Code::KeyF,
+            ],
+            Code::KeyT => &[
+                Code::KeyR,
+                Code::KeyY,
+                Code::Digit5,
+                Code::Digit6,
+                Code::KeyF,
+                Code::KeyG,
+            ],
+            Code::KeyY => &[
+                Code::KeyT,
+                Code::KeyU,
+                Code::Digit6,
+                Code::Digit7,
+                Code::KeyG,
+                Code::KeyH,
+            ],
+            Code::KeyU => &[
+                Code::KeyY,
+                Code::KeyI,
+                Code::Digit7,
+                Code::Digit8,
+                Code::KeyH,
+                Code::KeyJ,
+            ],
+            Code::KeyI => &[
+                Code::KeyU,
+                Code::KeyO,
+                Code::Digit8,
+                Code::Digit9,
+                Code::KeyJ,
+                Code::KeyK,
+            ],
+            Code::KeyO => &[
+                Code::KeyI,
+                Code::KeyP,
+                Code::Digit9,
+                Code::Digit0,
+                Code::KeyK,
+                Code::KeyL,
+            ],
+            Code::KeyP => &[
+                Code::KeyO,
+                Code::BracketLeft,
+                Code::Digit0,
+                Code::Minus,
+                Code::KeyL,
+                Code::Semicolon,
+            ],
+            Code::BracketLeft => &[
+                Code::KeyP,
+                Code::BracketRight,
+                Code::Minus,
+                Code::Equal,
+                Code::Semicolon,
+                Code::Quote,
+            ],
+            Code::BracketRight => &[Code::BracketLeft, Code::Backslash, Code::Equal, Code::Quote],
+            Code::Backslash => &[Code::BracketRight],
+            Code::KeyA => &[Code::KeyS, Code::KeyQ, Code::KeyW, Code::KeyZ],
+            Code::KeyS => &[
+                Code::KeyA,
+                Code::KeyD,
+                Code::KeyW,
+                Code::KeyE,
+                Code::KeyZ,
+                Code::KeyX,
+            ],
+            Code::KeyD => &[
+                Code::KeyS,
+                Code::KeyF,
+                Code::KeyE,
+                Code::KeyR,
+                Code::KeyX,
+                Code::KeyC,
+            ],
+            Code::KeyF => &[
+                Code::KeyD,
+                Code::KeyG,
+                Code::KeyR,
+                Code::KeyT,
+                Code::KeyC,
+                Code::KeyV,
+            ],
+            Code::KeyG => &[
+                Code::KeyF,
+                Code::KeyH,
+                Code::KeyT,
+                Code::KeyY,
+                Code::KeyV,
+                Code::KeyB,
+            ],
+            Code::KeyH => &[
+                Code::KeyG,
+                Code::KeyJ,
+                Code::KeyY,
+                Code::KeyU,
+                Code::KeyB,
+                Code::KeyN,
+            ],
+            Code::KeyJ => &[
+                Code::KeyH,
+                Code::KeyK,
+                Code::KeyU,
+                Code::KeyI,
+                Code::KeyN,
+                Code::KeyM,
+            ],
+            Code::KeyK => &[
+                Code::KeyJ,
+                Code::KeyL,
+                Code::KeyI,
+                Code::KeyO,
+                Code::KeyM,
+                Code::Comma,
+            ],
+            Code::KeyL => &[
+                Code::KeyK,
+                Code::Semicolon,
+                Code::KeyO,
+                Code::KeyP,
+                Code::Comma,
+                Code::Period,
+            ],
+            Code::Semicolon => &[
+                Code::KeyL,
+                Code::Quote,
+                Code::KeyP,
+                Code::BracketLeft,
+                Code::Period,
+                Code::Slash,
+            ],
+            Code::Quote => &[
+                Code::Semicolon,
+                Code::BracketLeft,
+                Code::BracketRight,
+                Code::Slash,
+            ],
+            Code::KeyZ => &[Code::KeyX, Code::KeyA, Code::KeyS],
+            Code::KeyX => &[Code::KeyZ, Code::KeyC, Code::KeyS, Code::KeyD],
+            Code::KeyC => &[Code::KeyX, Code::KeyV, Code::KeyD, Code::KeyF],
+            Code::KeyV => &[Code::KeyC, Code::KeyB, Code::KeyF, Code::KeyG],
+            Code::KeyB => &[Code::KeyV, Code::KeyN, Code::KeyG, Code::KeyH],
+            Code::KeyN => &[Code::KeyB, Code::KeyM, Code::KeyH, Code::KeyJ],
+            Code::KeyM => &[Code::KeyN, Code::Comma, Code::KeyJ, Code::KeyK],
+            Code::Comma => &[Code::KeyM, Code::Period, Code::KeyK, Code::KeyL],
+            Code::Period => &[Code::Comma, Code::Slash, Code::KeyL, Code::Semicolon],
+            Code::Slash => &[Code::Period, Code::Semicolon, Code::Quote],
+            _ => &[],
+        }
+    }
+
+    /// The home row key of the finger that types this key in touch typing.
+    ///
+    /// Uses the US QWERTY layout with the common finger assignment: the index
+    /// fingers rest on [`Code::KeyF`] and [`Code::KeyJ`] and also cover the
+    /// two inner columns, the pinkies cover all keys to their outside.
+    /// For example [`Code::KeyR`] and [`Code::KeyG`] return [`Code::KeyF`].
+    /// Keys outside of the digit, letter and punctuation rows return `None`.
+    pub fn home_row_anchor(self) -> Option<Code> {
+        let anchor = match self {
+            Code::Backquote | Code::Digit1 | Code::KeyQ | Code::KeyA | Code::KeyZ => Code::KeyA,
+            Code::Digit2 | Code::KeyW | Code::KeyS | Code::KeyX => Code::KeyS,
+            Code::Digit3 | Code::KeyE | Code::KeyD | Code::KeyC => Code::KeyD,
+            Code::Digit4
+            | Code::Digit5
+            | Code::KeyR
+            | Code::KeyT
+            | Code::KeyF
+            | Code::KeyG
+            | Code::KeyV
+            | Code::KeyB => Code::KeyF,
+            Code::Digit6
+            | Code::Digit7
+            | Code::KeyY
+            | Code::KeyU
+            | Code::KeyH
+            | Code::KeyJ
+            | Code::KeyN
+            | Code::KeyM => Code::KeyJ,
+            Code::Digit8 | Code::KeyI | Code::KeyK | Code::Comma => Code::KeyK,
+            Code::Digit9 | Code::KeyO | Code::KeyL | Code::Period => Code::KeyL,
+            Code::Digit0
+            | Code::Minus
+            | Code::Equal
+            | Code::KeyP
+            | Code::BracketLeft
+            | Code::BracketRight
+            | Code::Backslash
+            | Code::Semicolon
+            | Code::Quote
+            | Code::Slash => Code::Semicolon,
+            _ => return None,
+        };
+        Some(anchor)
+    }
+
+    /// Return `true` for the keys that toggle a lock state:
+    /// [`Code::CapsLock`], [`Code::NumLock`] and [`Code::ScrollLock`].
+    pub fn is_lock_key(self) -> bool {
+        matches!(self, Code::CapsLock | Code::NumLock | Code::ScrollLock)
+    }
+
+    /// Return `true` for the extra key of ISO keyboards, [`Code::IntlBackslash`].
+    ///
+    /// ISO (102-key) keyboards have a key between the left <kbd>Shift</kbd>
+    /// and <kbd>Z</kbd> keys that ANSI (101-key) keyboards lack, which makes
+    /// their left <kbd>Shift</kbd> key shorter. It is distinct from
+    /// [`Code::Backslash`], which is above <kbd>Enter</kbd> on ANSI keyboards and
+    /// left of <kbd>Enter</kbd> on ISO keyboards.
+    pub fn is_iso_extra_key(self) -> bool {
+        self == Code::IntlBackslash
+    }
+
+    /// Return `true` if this is the spacebar.
+    ///
+    /// The key value of the spacebar is `Key::Character(" ")`, not a
+    /// [`NamedKey`]. The specification intentionally has no named key for it
+    /// because the space is a printable character.
+    pub fn is_space(self) -> bool {
+        self == Code::Space
+    }
+
+    /// Return `true` for both the main and the numpad <kbd>Enter</kbd> key.
+    pub fn is_enter(self) -> bool {
+        matches!(self, Code::Enter | Code::NumpadEnter)
+    }
+
+    /// Replace [`Code::NumpadEnter`] with [`Code::Enter`].
+    ///
+    /// Other codes are returned unchanged. Use
+    /// [`KeyboardEvent::fold_numpad`](crate::KeyboardEvent::fold_numpad) to
+    /// fold all numpad keys of an event.
+    pub fn normalize_enter(self) -> Code {
+        match self {
+            Code::NumpadEnter => Code::Enter,
+            code => code,
+        }
+    }
+
+    /// The number of a function key, e.g. `13` for [`Code::F13`].
+    ///
+    /// Returns `None` for all other keys.
+    pub fn function_number(self) -> Option<u8> {
+        Code::FUNCTION_KEYS
+            .iter()
+            .position(|&code| code == self)
+            .map(|i| i as u8 + 1)
+    }
+
+    /// The function key with the given number, from [`Code::F1`] to [`Code::F35`].
+    pub fn from_function_number(number: u8) -> Option<Code> {
+        let index = usize::from(number).checked_sub(1)?;
+        Code::FUNCTION_KEYS.get(index).copied()
+    }
+
+    /// Return `true` if the key is located on the numeric keypad.
+    ///
+    /// The `NumLock` key is not considered part of the numeric keypad.
+    pub fn is_numpad(self) -> bool {
+        Code::NUMPAD.contains(&self)
+    }
+
+    /// The key value of a numpad key that depends on the `NumLock` state.
+    ///
+    /// With `NumLock` on the digit and decimal keys produce characters,
+    /// otherwise they act as navigation and editing keys
+    /// (e.g. <kbd>1</kbd> becomes <kbd>End</kbd>).
+    ///
+    /// Returns `None` for all keys not affected by `NumLock`.
+    pub fn numpad_with_numlock(self, num_lock: bool) -> Option<Key> {
+        let (character, named) = match self {
+            Code::Numpad0 => ("0", NamedKey::Insert),
+            Code::Numpad1 => ("1", NamedKey::End),
+            Code::Numpad2 => ("2", NamedKey::ArrowDown),
+            Code::Numpad3 => ("3", NamedKey::PageDown),
+            Code::Numpad4 => ("4", NamedKey::ArrowLeft),
+            Code::Numpad5 => ("5", NamedKey::Clear),
+            Code::Numpad6 => ("6", NamedKey::ArrowRight),
+            Code::Numpad7 => ("7", NamedKey::Home),
+            Code::Numpad8 => ("8", NamedKey::ArrowUp),
+            Code::Numpad9 => ("9", NamedKey::PageUp),
+            Code::NumpadDecimal => (".", NamedKey::Delete),
+            _ => return None,
+        };
+        Some(if num_lock {
+            Key::Character(character.to_string())
+        } else {
+            Key::Named(named)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{layout_consistent, Modifiers};
+    use alloc::format;
+
+    #[test]
+    fn as_ref_str() {
+        fn name<T: AsRef<str>>(value: T) -> alloc::string::String {
+            value.as_ref().to_string()
+        }
+
+        assert_eq!(name(Code::KeyA), "KeyA");
+        assert_eq!(name(NamedKey::ArrowUp), "ArrowUp");
+        assert_eq!(name(Code::MetaLeft), Code::MetaLeft.to_string());
+    }
+
+    #[test]
+    fn as_str_is_ascii() {
+        for &code in Code::all() {
+            assert!(code.as_str().is_ascii(), "{:?}", code);
+            assert_eq!(Code::from_str(code.as_str()).ok(), Some(code));
+        }
+    }
+
+    #[test]
+    fn is_space() {
+        assert!(Code::Space.is_space());
+        assert!(!Code::Enter.is_space());
+        assert_eq!(
+            Key::from_str(" ").ok(),
+            Some(Key::Character(" ".to_string()))
+        );
+        assert!(NamedKey::from_str("Space").is_err());
+        assert!(layout_consistent(
+            Code::Space,
+            &Key::Character(" ".into()),
+            Modifiers::empty()
+        ));
+    }
+
+    #[test]
+    fn laptop_keys() {
+        let codes = [
+            Code::Fn,
+            Code::FnLock,
+            Code::WakeUp,
+            Code::Sleep,
+            Code::Power,
+            Code::BrightnessUp,
+            Code::BrightnessDown,
+            Code::Eject,
+            Code::LaunchApp1,
+        ];
+        for code in codes {
+            assert_eq!(Code::from_str(code.to_string().as_str()).ok(), Some(code));
+            assert!(Code::all().contains(&code));
+        }
+    }
+
+    #[test]
+    fn home_row_anchor() {
+        assert_eq!(Code::KeyR.home_row_anchor(), Some(Code::KeyF));
+        assert_eq!(Code::KeyU.home_row_anchor(), Some(Code::KeyJ));
+        assert_eq!(Code::KeyZ.home_row_anchor(), Some(Code::KeyA));
+        assert_eq!(Code::Period.home_row_anchor(), Some(Code::KeyL));
+        assert_eq!(Code::Quote.home_row_anchor(), Some(Code::Semicolon));
+        assert_eq!(Code::Space.home_row_anchor(), None);
+        for &code in Code::all() {
+            if let Some(anchor) = code.home_row_anchor() {
+                assert_eq!(anchor.home_row_anchor(), Some(anchor));
+            }
+        }
+    }
+
+    #[test]
+    fn clusters() {
+        assert_eq!(
+            Code::ARROWS,
+            [
+                Code::ArrowLeft,
+                Code::ArrowUp,
+                Code::ArrowRight,
+                Code::ArrowDown
+            ]
+        );
+        assert_eq!(Code::FUNCTION_KEYS.len(), 35);
+        assert!(Code::NUMPAD.iter().all(|code| code.is_numpad()));
+        assert!(Code::MODIFIERS.contains(&Code::ShiftRight));
+        assert!(!Code::MODIFIERS.contains(&Code::CapsLock));
+    }
+
+    #[test]
+    fn function_keys() {
+        for (number, code) in [(1, Code::F1), (13, Code::F13), (24, Code::F24)] {
+            assert_eq!(code.function_number(), Some(number));
+            assert_eq!(Code::from_function_number(number), Some(code));
+        }
+        assert_eq!(Code::from_function_number(0), None);
+        assert_eq!(Code::from_function_number(36), None);
+        assert_eq!(Code::KeyF.function_number(), None);
+        for name in ["F13", "F24"] {
+            let code: Code = name.parse().unwrap();
+            assert_eq!(code.to_string(), name);
+            assert!(Code::all().contains(&code));
+        }
+        for number in 13..=24 {
+            let code = Code::from_function_number(number).unwrap();
+            assert_eq!(code.to_string(), format!("F{}", number));
+        }
+    }
+
+    #[test]
+    fn is_lock_key() {
+        assert!(Code::CapsLock.is_lock_key());
+        assert!(Code::NumLock.is_lock_key());
+        assert!(Code::ScrollLock.is_lock_key());
+        assert!(!Code::ShiftLeft.is_lock_key());
+    }
+
+    #[test]
+    fn is_iso_extra_key() {
+        assert!(Code::IntlBackslash.is_iso_extra_key());
+        assert!(!Code::Backslash.is_iso_extra_key());
+        assert!(!Code::ShiftLeft.is_iso_extra_key());
+        for (code, name) in [
+            (Code::Backslash, "Backslash"),
+            (Code::IntlBackslash, "IntlBackslash"),
+        ] {
+            assert_eq!(code.to_string(), name);
+            assert_eq!(name.parse::<Code>().unwrap(), code);
+        }
+    }
+
+    #[test]
+    fn enter() {
+        assert!(Code::Enter.is_enter());
+        assert!(Code::NumpadEnter.is_enter());
+        assert!(!Code::Space.is_enter());
+        assert_eq!(Code::Enter.normalize_enter(), Code::Enter);
+        assert_eq!(Code::NumpadEnter.normalize_enter(), Code::Enter);
+        assert_eq!(Code::Numpad1.normalize_enter(), Code::Numpad1);
+    }
+
+    #[test]
+    fn numpad_keys() {
+        let codes = [
+            Code::NumpadBackspace,
+            Code::NumpadClear,
+            Code::NumpadClearEntry,
+            Code::NumpadComma,
+            Code::NumpadEqual,
+            Code::NumpadHash,
+            Code::NumpadMemoryAdd,
+            Code::NumpadMemoryClear,
+            Code::NumpadMemoryRecall,
+            Code::NumpadMemoryStore,
+            Code::NumpadMemorySubtract,
+            Code::NumpadParenLeft,
+            Code::NumpadParenRight,
+            Code::NumpadStar,
+        ];
+        for code in codes {
+            assert_eq!(Code::from_str(code.to_string().as_str()).ok(), Some(code));
+            assert!(Code::all().contains(&code));
+            assert!(code.is_numpad());
+        }
+        // All numpad codes of the specification except `NumLock`.
+        assert_eq!(
+            Code::all().iter().filter(|code| code.is_numpad()).count(),
+            30
+        );
+    }
+
+    #[test]
+    fn ime_keys() {
+        let codes = [
+            Code::IntlBackslash,
+            Code::IntlRo,
+            Code::IntlYen,
+            Code::Convert,
+            Code::NonConvert,
+            Code::KanaMode,
+            Code::Lang1,
+            Code::Lang2,
+            Code::Lang3,
+            Code::Lang4,
+            Code::Lang5,
+        ];
+        for code in codes {
+            assert_eq!(Code::from_str(code.to_string().as_str()).ok(), Some(code));
+            assert!(Code::all().contains(&code));
+        }
+    }
+
+    #[test]
+    fn from_str_lenient() {
+        assert_eq!(
+            Code::from_str_lenient("SuperLeft").ok(),
+            Some(Code::MetaLeft)
+        );
+        assert_eq!(
+            Code::from_str_lenient("HyperLeft").ok(),
+            Some(Code::MetaLeft)
+        );
+        assert_eq!(
+            Code::from_str_lenient("SuperRight").ok(),
+            Some(Code::MetaRight)
+        );
+        assert_eq!(
+            Code::from_str_lenient("HyperRight").ok(),
+            Some(Code::MetaRight)
+        );
+        assert_eq!(Code::from_str_lenient("KeyA").ok(), Some(Code::KeyA));
+        assert!(Code::from_str_lenient("KyeA").is_err());
+        assert!(Code::from_str("SuperLeft").is_err());
+    }
+
+    #[test]
+    fn from_str_lenient_vendor_prefix() {
+        assert_eq!(Code::from_str_lenient("MozKeyA").ok(), Some(Code::KeyA));
+        assert_eq!(
+            Code::from_str_lenient("WebKitSuperLeft").ok(),
+            Some(Code::MetaLeft)
+        );
+        let err = Code::from_str_lenient("MozKyeA").unwrap_err();
+        assert_eq!(err.input(), "MozKyeA");
+    }
+
+    #[test]
+    fn from_str_lenient_numpad() {
+        let aliases = [
+            ("NumpadPlus", Code::NumpadAdd, "NumpadAdd"),
+            ("NumpadMinus", Code::NumpadSubtract, "NumpadSubtract"),
+            ("NumpadSlash", Code::NumpadDivide, "NumpadDivide"),
+        ];
+        for (alias, code, canonical) in aliases {
+            assert_eq!(Code::from_str_lenient(alias).ok(), Some(code));
+            assert_eq!(code.to_string(), canonical);
+            assert!(Code::from_str(alias).is_err());
+        }
+        assert_eq!(
+            Code::from_str_lenient("NumpadStar").ok(),
+            Some(Code::NumpadStar)
+        );
+    }
+
+    #[test]
+    fn from_named_key_and_location() {
+        let resolve = Code::from_named_key_and_location;
+        assert_eq!(
+            resolve(NamedKey::Shift, Location::Left),
+            Some(Code::ShiftLeft)
+        );
+        assert_eq!(
+            resolve(NamedKey::Shift, Location::Right),
+            Some(Code::ShiftRight)
+        );
+        assert_eq!(resolve(NamedKey::Shift, Location::Standard), None);
+        assert_eq!(
+            resolve(NamedKey::End, Location::Numpad),
+            Some(Code::Numpad1)
+        );
+        assert_eq!(resolve(NamedKey::End, Location::Standard), Some(Code::End));
+        assert_eq!(resolve(NamedKey::F1, Location::Standard), None);
+    }
+
+    #[test]
+    fn is_dead_key_candidate() {
+        assert!(Code::Backquote.is_dead_key_candidate());
+        assert!(Code::Quote.is_dead_key_candidate());
+        assert!(Code::BracketLeft.is_dead_key_candidate());
+        assert!(!Code::KeyA.is_dead_key_candidate());
+        assert!(!Code::Digit1.is_dead_key_candidate());
+        assert!(!Code::Space.is_dead_key_candidate());
+        assert!(!Code::Enter.is_dead_key_candidate());
+    }
+
+    #[test]
+    fn os_keys() {
+        assert_eq!(Code::from_str_lenient("OSLeft").ok(), Some(Code::MetaLeft));
+        assert_eq!(
+            Code::from_str_lenient("OSRight").ok(),
+            Some(Code::MetaRight)
+        );
+        assert_eq!(Code::from_str("OSLeft").ok(), Some(Code::MetaLeft));
+        assert_eq!(Code::from_str("OSRight").ok(), Some(Code::MetaRight));
+    }
+
+    #[test]
+    fn neighbors() {
+        let mut neighbors = Code::KeyG.neighbors().to_vec();
+        neighbors.sort();
+        assert_eq!(
+            neighbors,
+            [
+                Code::KeyB,
+                Code::KeyF,
+                Code::KeyH,
+                Code::KeyT,
+                Code::KeyV,
+                Code::KeyY
+            ]
+        );
+        assert_eq!(
+            Code::KeyQ.neighbors(),
+            [Code::KeyW, Code::Digit1, Code::Digit2, Code::KeyA]
+        );
+        assert!(Code::Backquote.neighbors().contains(&Code::Digit1));
+        assert!(Code::Enter.neighbors().is_empty());
+    }
+
+    #[test]
+    fn numpad_with_numlock() {
+        let cases = [
+            (Code::Numpad1, "1", NamedKey::End),
+            (Code::Numpad5, "5", NamedKey::Clear),
+            (Code::Numpad8, "8", NamedKey::ArrowUp),
+            (Code::NumpadDecimal, ".", NamedKey::Delete),
+        ];
+        for (code, character, named) in cases {
+            assert!(code.is_numpad());
+            assert_eq!(
+                code.numpad_with_numlock(true),
+                Some(Key::Character(character.to_string()))
+            );
+            assert_eq!(code.numpad_with_numlock(false), Some(Key::Named(named)));
+        }
+        assert!(Code::NumpadAdd.is_numpad());
+        assert_eq!(Code::NumpadAdd.numpad_with_numlock(false), None);
+        assert!(!Code::NumLock.is_numpad());
+        assert!(!Code::Digit1.is_numpad());
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn json_schema() {
+        use alloc::vec::Vec;
+
+        let schema = serde_json::to_value(schemars::schema_for!(Code)).unwrap();
+        let mut values: Vec<&str> = Vec::new();
+        for variant in schema["oneOf"].as_array().unwrap() {
+            if let Some(value) = variant["const"].as_str() {
+                values.push(value);
+            }
+            for value in variant["enum"].as_array().into_iter().flatten() {
+                values.push(value.as_str().unwrap());
+            }
+        }
+        values.sort_unstable();
+        let mut expected: Vec<&str> = Code::all().iter().map(|c| c.as_str()).collect();
+        expected.sort_unstable();
+        assert_eq!(values, expected);
+    }
+}
@@ -1,3 +1,5 @@
+//! Composition events of input methods.
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -55,6 +57,8 @@ impl CompositionState {
 /// A composition session is always started by a [`CompositionState::Start`]
 /// event followed by zero or more [`CompositionState::Update`] events
 /// and terminated by a single [`CompositionState::End`] event.
+///
+/// Use [`dom_names`] to serialize the state like the DOM as a `type` field.
 #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CompositionEvent {
@@ -63,3 +67,156 @@ pub struct CompositionEvent {
     /// Current composition data. May be empty.
     pub data: String,
 }
+
+/// Serialize a [`CompositionEvent`] like the DOM, with the state as a `type`
+/// field holding the [event type](CompositionState::event_type).
+///
+/// Use it with `#[serde(with = "keyboard_types::composition::dom_names")]`.
+///
+/// ```rust
+/// use keyboard_types::{CompositionEvent, CompositionState};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Message {
+///     #[serde(with = "keyboard_types::composition::dom_names")]
+///     event: CompositionEvent,
+/// }
+///
+/// let event = CompositionEvent {
+///     state: CompositionState::Start,
+///     data: String::new(),
+/// };
+/// let json = serde_json::to_string(&Message { event }).unwrap();
+/// assert_eq!(json, r#"{"event":{"type":"compositionstart","data":""}}"#);
+/// ```
+#[cfg(feature = "serde")]
+pub mod dom_names {
+    use super::{CompositionEvent, CompositionState};
+    use alloc::string::String;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize)]
+    #[serde(rename = "CompositionEvent")]
+    struct DomCompositionEventRef<'a> {
+        #[serde(rename = "type", with = "event_type")]
+        state: CompositionState,
+        data: &'a str,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "CompositionEvent")]
+    struct DomCompositionEvent {
+        #[serde(rename = "type", with = "event_type")]
+        state: CompositionState,
+        data: String,
+    }
+
+    /// Serialize the event with a DOM `type` field.
+    pub fn serialize<S: Serializer>(event: &CompositionEvent, s: S) -> Result<S::Ok, S::Error> {
+        DomCompositionEventRef {
+            state: event.state,
+            data: &event.data,
+        }
+        .serialize(s)
+    }
+
+    /// Deserialize the event from a DOM `type` field.
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<CompositionEvent, D::Error> {
+        let event = DomCompositionEvent::deserialize(d)?;
+        Ok(CompositionEvent {
+            state: event.state,
+            data: event.data,
+        })
+    }
+
+    /// Serialize a [`CompositionState`] as its DOM event type.
+    mod event_type {
+        use crate::CompositionState;
+        use alloc::string::String;
+        use serde::de::{self, Deserialize, Deserializer};
+        use serde::Serializer;
+
+        pub fn serialize<S: Serializer>(state: &CompositionState, s: S) -> Result<S::Ok, S::Error> {
+            s.serialize_str(state.event_type())
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<CompositionState, D::Error> {
+            let event_type = String::deserialize(d)?;
+            [
+                CompositionState::Start,
+                CompositionState::Update,
+                CompositionState::End,
+            ]
+            .into_iter()
+            .find(|state| state.event_type() == event_type)
+            .ok_or_else(|| {
+                de::Error::unknown_variant(
+                    &event_type,
+                    &["compositionstart", "compositionupdate", "compositionend"],
+                )
+            })
+        }
+    }
+}
+
+impl CompositionEvent {
+    /// Return `true` if the composition data is empty.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// The length of the composition data in bytes.
+    pub fn data_len(&self) -> usize {
+        self.data.len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn is_empty() {
+        let event = CompositionEvent {
+            state: CompositionState::Start,
+            data: String::new(),
+        };
+        assert!(event.is_empty());
+        assert_eq!(event.data_len(), 0);
+        let event = CompositionEvent {
+            state: CompositionState::Update,
+            data: "かな".to_string(),
+        };
+        assert!(!event.is_empty());
+        assert_eq!(event.data_len(), 6);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_dom_names() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Message {
+            #[serde(with = "super::dom_names")]
+            event: CompositionEvent,
+        }
+
+        let message = Message {
+            event: CompositionEvent {
+                state: CompositionState::Start,
+                data: "a".to_string(),
+            },
+        };
+        let json = r#"{"event":{"type":"compositionstart","data":"a"}}"#;
+        assert_eq!(serde_json::to_string(&message).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Message>(json).unwrap(), message);
+        let json = r#"{"event":{"type":"keydown","data":"a"}}"#;
+        assert!(serde_json::from_str::<Message>(json).is_err());
+        let json = r#"{"state":"Start","data":"a"}"#;
+        assert_eq!(
+            serde_json::from_str::<CompositionEvent>(json).unwrap(),
+            message.event
+        );
+    }
+}
@@ -0,0 +1,168 @@
+//! Composition of dead keys with the following key.
+
+use crate::Key;
+
+/// Compositions of an accent with a base character, as `(accent, base, composed)`.
+const COMPOSITIONS: &[(char, char, char)] = &[
+    ('´', 'a', 'á'),
+    ('´', 'e', 'é'),
+    ('´', 'i', 'í'),
+    ('´', 'o', 'ó'),
+    ('´', 'u', 'ú'),
+    ('´', 'y', 'ý'),
+    ('´', 'A', 'Á'),
+    ('´', 'E', 'É'),
+    ('´', 'I', 'Í'),
+    ('´', 'O', 'Ó'),
+    ('´', 'U', 'Ú'),
+    ('´', 'Y', 'Ý'),
+    ('`', 'a', 'à'),
+    ('`', 'e', 'è'),
+    ('`', 'i', 'ì'),
+    ('`', 'o', 'ò'),
+    ('`', 'u', 'ù'),
+    ('`', 'A', 'À'),
+    ('`', 'E', 'È'),
+    ('`', 'I', 'Ì'),
+    ('`', 'O', 'Ò'),
+    ('`', 'U', 'Ù'),
+    ('^', 'a', 'â'),
+    ('^', 'e', 'ê'),
+    ('^', 'i', 'î'),
+    ('^', 'o', 'ô'),
+    ('^', 'u', 'û'),
+    ('^', 'A', 'Â'),
+    ('^', 'E', 'Ê'),
+    ('^', 'I', 'Î'),
+    ('^', 'O', 'Ô'),
+    ('^', 'U', 'Û'),
+    ('¨', 'a', 'ä'),
+    ('¨', 'e', 'ë'),
+    ('¨', 'i', 'ï'),
+    ('¨', 'o', 'ö'),
+    ('¨', 'u', 'ü'),
+    ('¨', 'y', 'ÿ'),
+    ('¨', 'A', 'Ä'),
+    ('¨', 'E', 'Ë'),
+    ('¨', 'I', 'Ï'),
+    ('¨', 'O', 'Ö'),
+    ('¨', 'U', 'Ü'),
+    ('~', 'a', 'ã'),
+    ('~', 'n', 'ñ'),
+    ('~', 'o', 'õ'),
+    ('~', 'A', 'Ã'),
+    ('~', 'N', 'Ñ'),
+    ('~', 'O', 'Õ'),
+    ('¸', 'c', 'ç'),
+    ('¸', 'C', 'Ç'),
+];
+
+/// Compose dead keys with the following key in software.
+///
+/// Useful where no input method composes the characters, e.g. in a terminal
+/// emulator. The dead key is given as the [`Key::Character`] of its spacing
+/// accent: `´`, `` ` ``, `^`, `¨`, `~` or `¸`. A built-in table covers the
+/// common Latin accents. Following the dead key with a space produces the
+/// accent itself.
+///
+/// ```rust
+/// # use keyboard_types::{DeadKeyComposer, Key};
+/// let mut composer = DeadKeyComposer::new();
+/// assert!(composer.set_dead(&Key::from('´')));
+/// assert_eq!(composer.compose(&Key::from('e')), Some(Key::from('é')));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DeadKeyComposer {
+    accent: Option<char>,
+}
+
+impl DeadKeyComposer {
+    /// Create a composer without a pending dead key.
+    pub fn new() -> DeadKeyComposer {
+        DeadKeyComposer { accent: None }
+    }
+
+    /// Start a composition with a dead key.
+    ///
+    /// Returns `false` and leaves the composer unchanged if the key is not
+    /// one of the supported accents.
+    pub fn set_dead(&mut self, key: &Key) -> bool {
+        let accent = match single_char(key) {
+            Some(accent) if COMPOSITIONS.iter().any(|&(a, _, _)| a == accent) => accent,
+            _ => return false,
+        };
+        self.accent = Some(accent);
+        true
+    }
+
+    /// Return `true` if a dead key is waiting for the base key.
+    pub fn is_pending(&self) -> bool {
+        self.accent.is_some()
+    }
+
+    /// Compose the pending dead key with the base key.
+    ///
+    /// The pending dead key is consumed in any case. Returns `None` if no
+    /// dead key is pending or the combination has no composition.
+    pub fn compose(&mut self, base: &Key) -> Option<Key> {
+        let accent = self.accent.take()?;
+        let base = single_char(base)?;
+        if base == ' ' {
+            return Some(Key::from(accent));
+        }
+        COMPOSITIONS
+            .iter()
+            .find(|&&(a, b, _)| a == accent && b == base)
+            .map(|&(_, _, composed)| Key::from(composed))
+    }
+}
+
+/// The character of a [`Key::Character`] with exactly one codepoint.
+fn single_char(key: &Key) -> Option<char> {
+    match key {
+        Key::Character(s) => {
+            let mut chars = s.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Some(c),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::NamedKey;
+
+    #[test]
+    fn compose() {
+        let mut composer = DeadKeyComposer::new();
+        assert!(!composer.is_pending());
+        assert_eq!(composer.compose(&Key::from('e')), None);
+
+        assert!(composer.set_dead(&Key::from('´')));
+        assert!(composer.is_pending());
+        assert_eq!(composer.compose(&Key::from('e')), Some(Key::from('é')));
+        assert!(!composer.is_pending());
+
+        assert!(composer.set_dead(&Key::from('~')));
+        assert_eq!(composer.compose(&Key::from(' ')), Some(Key::from('~')));
+    }
+
+    #[test]
+    fn unknown() {
+        let mut composer = DeadKeyComposer::new();
+        assert!(!composer.set_dead(&Key::from('x')));
+        assert!(!composer.set_dead(&Key::Named(NamedKey::Dead)));
+        assert!(!composer.is_pending());
+
+        assert!(composer.set_dead(&Key::from('¸')));
+        assert_eq!(composer.compose(&Key::from('e')), None);
+        assert!(!composer.is_pending());
+
+        assert!(composer.set_dead(&Key::from('^')));
+        assert_eq!(composer.compose(&Key::Named(NamedKey::Enter)), None);
+    }
+}
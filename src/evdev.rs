@@ -0,0 +1,259 @@
+//! Conversions from and to Linux evdev key codes.
+//!
+//! Specification: <https://github.com/torvalds/linux/blob/master/include/uapi/linux/input-event-codes.h>
+
+use crate::Code;
+
+/// Pairs of evdev key codes and the corresponding [`Code`].
+///
+/// Based on the mapping used by Chromium and Firefox.
+const EVDEV_CODES: &[(u16, Code)] = &[
+    (1, Code::Escape),
+    (2, Code::Digit1),
+    (3, Code::Digit2),
+    (4, Code::Digit3),
+    (5, Code::Digit4),
+    (6, Code::Digit5),
+    (7, Code::Digit6),
+    (8, Code::Digit7),
+    (9, Code::Digit8),
+    (10, Code::Digit9),
+    (11, Code::Digit0),
+    (12, Code::Minus),
+    (13, Code::Equal),
+    (14, Code::Backspace),
+    (15, Code::Tab),
+    (16, Code::KeyQ),
+    (17, Code::KeyW),
+    (18, Code::KeyE),
+    (19, Code::KeyR),
+    (20, Code::KeyT),
+    (21, Code::KeyY),
+    (22, Code::KeyU),
+    (23, Code::KeyI),
+    (24, Code::KeyO),
+    (25, Code::KeyP),
+    (26, Code::BracketLeft),
+    (27, Code::BracketRight),
+    (28, Code::Enter),
+    (29, Code::ControlLeft),
+    (30, Code::KeyA),
+    (31, Code::KeyS),
+    (32, Code::KeyD),
+    (33, Code::KeyF),
+    (34, Code::KeyG),
+    (35, Code::KeyH),
+    (36, Code::KeyJ),
+    (37, Code::KeyK),
+    (38, Code::KeyL),
+    (39, Code::Semicolon),
+    (40, Code::Quote),
+    (41, Code::Backquote),
+    (42, Code::ShiftLeft),
+    (43, Code::Backslash),
+    (44, Code::KeyZ),
+    (45, Code::KeyX),
+    (46, Code::KeyC),
+    (47, Code::KeyV),
+    (48, Code::KeyB),
+    (49, Code::KeyN),
+    (50, Code::KeyM),
+    (51, Code::Comma),
+    (52, Code::Period),
+    (53, Code::Slash),
+    (54, Code::ShiftRight),
+    (55, Code::NumpadMultiply),
+    (56, Code::AltLeft),
+    (57, Code::Space),
+    (58, Code::CapsLock),
+    (59, Code::F1),
+    (60, Code::F2),
+    (61, Code::F3),
+    (62, Code::F4),
+    (63, Code::F5),
+    (64, Code::F6),
+    (65, Code::F7),
+    (66, Code::F8),
+    (67, Code::F9),
+    (68, Code::F10),
+    (69, Code::NumLock),
+    (70, Code::ScrollLock),
+    (71, Code::Numpad7),
+    (72, Code::Numpad8),
+    (73, Code::Numpad9),
+    (74, Code::NumpadSubtract),
+    (75, Code::Numpad4),
+    (76, Code::Numpad5),
+    (77, Code::Numpad6),
+    (78, Code::NumpadAdd),
+    (79, Code::Numpad1),
+    (80, Code::Numpad2),
+    (81, Code::Numpad3),
+    (82, Code::Numpad0),
+    (83, Code::NumpadDecimal),
+    (85, Code::Lang5),
+    (86, Code::IntlBackslash),
+    (87, Code::F11),
+    (88, Code::F12),
+    (89, Code::IntlRo),
+    (90, Code::Lang3),
+    (91, Code::Lang4),
+    (92, Code::Convert),
+    (93, Code::KanaMode),
+    (94, Code::NonConvert),
+    (96, Code::NumpadEnter),
+    (97, Code::ControlRight),
+    (98, Code::NumpadDivide),
+    (99, Code::PrintScreen),
+    (100, Code::AltRight),
+    (102, Code::Home),
+    (103, Code::ArrowUp),
+    (104, Code::PageUp),
+    (105, Code::ArrowLeft),
+    (106, Code::ArrowRight),
+    (107, Code::End),
+    (108, Code::ArrowDown),
+    (109, Code::PageDown),
+    (110, Code::Insert),
+    (111, Code::Delete),
+    (113, Code::AudioVolumeMute),
+    (114, Code::AudioVolumeDown),
+    (115, Code::AudioVolumeUp),
+    (116, Code::Power),
+    (117, Code::NumpadEqual),
+    (119, Code::Pause),
+    (120, Code::ShowAllWindows),
+    (121, Code::NumpadComma),
+    (122, Code::Lang1),
+    (123, Code::Lang2),
+    (124, Code::IntlYen),
+    (125, Code::MetaLeft),
+    (126, Code::MetaRight),
+    (127, Code::ContextMenu),
+    (128, Code::BrowserStop),
+    (129, Code::Again),
+    (130, Code::Props),
+    (131, Code::Undo),
+    (132, Code::Select),
+    (133, Code::Copy),
+    (134, Code::Open),
+    (135, Code::Paste),
+    (136, Code::Find),
+    (137, Code::Cut),
+    (138, Code::Help),
+    (140, Code::LaunchApp2),
+    (142, Code::Sleep),
+    (143, Code::WakeUp),
+    (144, Code::LaunchApp1),
+    (155, Code::LaunchMail),
+    (156, Code::BrowserFavorites),
+    (158, Code::BrowserBack),
+    (159, Code::BrowserForward),
+    (161, Code::Eject),
+    (163, Code::MediaTrackNext),
+    (164, Code::MediaPlayPause),
+    (165, Code::MediaTrackPrevious),
+    (166, Code::MediaStop),
+    (167, Code::MediaRecord),
+    (168, Code::MediaRewind),
+    (171, Code::MediaSelect),
+    (172, Code::BrowserHome),
+    (173, Code::BrowserRefresh),
+    (179, Code::NumpadParenLeft),
+    (180, Code::NumpadParenRight),
+    (183, Code::F13),
+    (184, Code::F14),
+    (185, Code::F15),
+    (186, Code::F16),
+    (187, Code::F17),
+    (188, Code::F18),
+    (189, Code::F19),
+    (190, Code::F20),
+    (191, Code::F21),
+    (192, Code::F22),
+    (193, Code::F23),
+    (194, Code::F24),
+    (200, Code::MediaPlay),
+    (201, Code::MediaPause),
+    (208, Code::MediaFastForward),
+    (217, Code::BrowserSearch),
+    (224, Code::BrightnessDown),
+    (225, Code::BrightnessUp),
+    (228, Code::KeyboardBacklightToggle),
+    (231, Code::MailSend),
+    (232, Code::MailReply),
+    (233, Code::MailForward),
+    (248, Code::MicrophoneMuteToggle),
+    (372, Code::ZoomToggle),
+    (464, Code::Fn),
+    (579, Code::LaunchControlPanel),
+    (581, Code::LaunchScreenSaver),
+    (583, Code::LaunchAssistant),
+    (584, Code::KeyboardLayoutSelect),
+    (633, Code::PrivacyScreenToggle),
+];
+
+/// Ranges of evdev button codes (`BTN_*`) for mice, joysticks and gamepads.
+const EVDEV_BUTTONS: &[core::ops::RangeInclusive<u16>] =
+    &[0x100..=0x15F, 0x220..=0x223, 0x2C0..=0x2E7];
+
+impl Code {
+    /// Get the code for a Linux evdev key code (`KEY_*`).
+    ///
+    /// Returns `None` if the key code has no corresponding [`Code`].
+    /// Button codes (`BTN_*`), including the D-pad and face buttons of
+    /// gamepads, always return `None`: they are not keyboard keys, even if
+    /// a device reports them through the keyboard subsystem.
+    pub fn from_evdev_keycode(keycode: u16) -> Option<Code> {
+        if EVDEV_BUTTONS.iter().any(|range| range.contains(&keycode)) {
+            return None;
+        }
+        EVDEV_CODES
+            .iter()
+            .find(|&&(k, _)| k == keycode)
+            .map(|&(_, code)| code)
+    }
+
+    /// Get the Linux evdev key code (`KEY_*`) for a code.
+    ///
+    /// Returns `None` if the code has no corresponding key code.
+    pub fn to_evdev_keycode(self) -> Option<u16> {
+        EVDEV_CODES
+            .iter()
+            .find(|&&(_, code)| code == self)
+            .map(|&(k, _)| k)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn evdev_keycode() {
+        assert_eq!(Code::from_evdev_keycode(30), Some(Code::KeyA));
+        assert_eq!(Code::from_evdev_keycode(125), Some(Code::MetaLeft));
+        assert_eq!(Code::from_evdev_keycode(0), None);
+        assert_eq!(Code::KeyA.to_evdev_keycode(), Some(30));
+        assert_eq!(Code::Unidentified.to_evdev_keycode(), None);
+    }
+
+    #[test]
+    fn buttons() {
+        // BTN_SOUTH, BTN_LEFT and BTN_DPAD_UP.
+        assert_eq!(Code::from_evdev_keycode(0x130), None);
+        assert_eq!(Code::from_evdev_keycode(0x110), None);
+        assert_eq!(Code::from_evdev_keycode(0x220), None);
+        for &(keycode, _) in EVDEV_CODES {
+            assert!(!EVDEV_BUTTONS.iter().any(|range| range.contains(&keycode)));
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        for &(keycode, code) in EVDEV_CODES {
+            assert_eq!(Code::from_evdev_keycode(keycode), Some(code));
+            assert_eq!(code.to_evdev_keycode(), Some(keycode));
+        }
+    }
+}
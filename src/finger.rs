@@ -0,0 +1,86 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Code;
+
+/// A finger used for touch typing.
+///
+/// Returned by [`Code::finger`].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Finger {
+    /// The pinky of the left hand.
+    LeftPinky,
+    /// The ring finger of the left hand.
+    LeftRing,
+    /// The middle finger of the left hand.
+    LeftMiddle,
+    /// The index finger of the left hand.
+    LeftIndex,
+    /// The index finger of the right hand.
+    RightIndex,
+    /// The middle finger of the right hand.
+    RightMiddle,
+    /// The ring finger of the right hand.
+    RightRing,
+    /// The pinky of the right hand.
+    RightPinky,
+    /// Either thumb.
+    Thumb,
+}
+
+impl Code {
+    /// The finger that types this key in touch typing.
+    ///
+    /// The keys of the digit, letter and punctuation rows use the finger
+    /// of their [home row anchor](Code::home_row_anchor). The spacebar and
+    /// the <kbd>Alt</kbd> and <kbd>Meta</kbd> keys next to it are typed with
+    /// the thumbs, the remaining modifier and editing keys at the sides of
+    /// the alphanumeric section with the pinkies. All other keys return `None`.
+    pub fn finger(self) -> Option<Finger> {
+        if let Some(anchor) = self.home_row_anchor() {
+            return Some(match anchor {
+                Code::KeyA => Finger::LeftPinky,
+                Code::KeyS => Finger::LeftRing,
+                Code::KeyD => Finger::LeftMiddle,
+                Code::KeyF => Finger::LeftIndex,
+                Code::KeyJ => Finger::RightIndex,
+                Code::KeyK => Finger::RightMiddle,
+                Code::KeyL => Finger::RightRing,
+                _ => Finger::RightPinky,
+            });
+        }
+        let finger = match self {
+            Code::Space | Code::AltLeft | Code::AltRight | Code::MetaLeft | Code::MetaRight => {
+                Finger::Thumb
+            }
+            Code::Tab
+            | Code::CapsLock
+            | Code::ShiftLeft
+            | Code::ControlLeft
+            | Code::IntlBackslash => Finger::LeftPinky,
+            Code::Backspace | Code::Enter | Code::ShiftRight | Code::ControlRight => {
+                Finger::RightPinky
+            }
+            _ => return None,
+        };
+        Some(finger)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn finger() {
+        assert_eq!(Code::KeyQ.finger(), Some(Finger::LeftPinky));
+        assert_eq!(Code::KeyG.finger(), Some(Finger::LeftIndex));
+        assert_eq!(Code::KeyN.finger(), Some(Finger::RightIndex));
+        assert_eq!(Code::Comma.finger(), Some(Finger::RightMiddle));
+        assert_eq!(Code::Slash.finger(), Some(Finger::RightPinky));
+        assert_eq!(Code::ShiftLeft.finger(), Some(Finger::LeftPinky));
+        assert_eq!(Code::Space.finger(), Some(Finger::Thumb));
+        assert_eq!(Code::ArrowUp.finger(), None);
+    }
+}
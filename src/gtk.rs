@@ -0,0 +1,184 @@
+//! Conversions from GTK/GDK key values and hardware key codes.
+//!
+//! Specification: <https://gitlab.gnome.org/GNOME/gtk/-/blob/main/gdk/gdkkeysyms.h>
+
+use crate::{Code, NamedKey};
+
+/// Pairs of GDK key values (`GDK_KEY_*`) and the corresponding [`NamedKey`].
+///
+/// Several key values, like the left and right modifiers or the keypad
+/// navigation keys, map to the same named key.
+const GDK_KEYVALS: &[(u32, NamedKey)] = &[
+    (0xfe03, NamedKey::AltGraph),               // ISO_Level3_Shift
+    (0xfe08, NamedKey::GroupNext),              // ISO_Next_Group
+    (0xfe0a, NamedKey::GroupPrevious),          // ISO_Prev_Group
+    (0xfe0c, NamedKey::GroupFirst),             // ISO_First_Group
+    (0xfe0e, NamedKey::GroupLast),              // ISO_Last_Group
+    (0xff08, NamedKey::Backspace),              // BackSpace
+    (0xff09, NamedKey::Tab),                    // Tab
+    (0xff0b, NamedKey::Clear),                  // Clear
+    (0xff0d, NamedKey::Enter),                  // Return
+    (0xff13, NamedKey::Pause),                  // Pause
+    (0xff14, NamedKey::ScrollLock),             // Scroll_Lock
+    (0xff1b, NamedKey::Escape),                 // Escape
+    (0xff20, NamedKey::Compose),                // Multi_key
+    (0xff21, NamedKey::KanjiMode),              // Kanji
+    (0xff22, NamedKey::NonConvert),             // Muhenkan
+    (0xff23, NamedKey::Convert),                // Henkan
+    (0xff24, NamedKey::Romaji),                 // Romaji
+    (0xff25, NamedKey::Hiragana),               // Hiragana
+    (0xff26, NamedKey::Katakana),               // Katakana
+    (0xff27, NamedKey::HiraganaKatakana),       // Hiragana_Katakana
+    (0xff28, NamedKey::Zenkaku),                // Zenkaku
+    (0xff29, NamedKey::Hankaku),                // Hankaku
+    (0xff2a, NamedKey::ZenkakuHankaku),         // Zenkaku_Hankaku
+    (0xff30, NamedKey::Alphanumeric),           // Eisu_toggle
+    (0xff31, NamedKey::HangulMode),             // Hangul
+    (0xff34, NamedKey::HanjaMode),              // Hangul_Hanja
+    (0xff50, NamedKey::Home),                   // Home
+    (0xff51, NamedKey::ArrowLeft),              // Left
+    (0xff52, NamedKey::ArrowUp),                // Up
+    (0xff53, NamedKey::ArrowRight),             // Right
+    (0xff54, NamedKey::ArrowDown),              // Down
+    (0xff55, NamedKey::PageUp),                 // Page_Up
+    (0xff56, NamedKey::PageDown),               // Page_Down
+    (0xff57, NamedKey::End),                    // End
+    (0xff60, NamedKey::Select),                 // Select
+    (0xff61, NamedKey::PrintScreen),            // Print
+    (0xff62, NamedKey::Execute),                // Execute
+    (0xff63, NamedKey::Insert),                 // Insert
+    (0xff65, NamedKey::Undo),                   // Undo
+    (0xff66, NamedKey::Redo),                   // Redo
+    (0xff67, NamedKey::ContextMenu),            // Menu
+    (0xff68, NamedKey::Find),                   // Find
+    (0xff69, NamedKey::Cancel),                 // Cancel
+    (0xff6a, NamedKey::Help),                   // Help
+    (0xff7e, NamedKey::ModeChange),             // Mode_switch
+    (0xff7f, NamedKey::NumLock),                // Num_Lock
+    (0xff89, NamedKey::Tab),                    // KP_Tab
+    (0xff8d, NamedKey::Enter),                  // KP_Enter
+    (0xff95, NamedKey::Home),                   // KP_Home
+    (0xff96, NamedKey::ArrowLeft),              // KP_Left
+    (0xff97, NamedKey::ArrowUp),                // KP_Up
+    (0xff98, NamedKey::ArrowRight),             // KP_Right
+    (0xff99, NamedKey::ArrowDown),              // KP_Down
+    (0xff9a, NamedKey::PageUp),                 // KP_Page_Up
+    (0xff9b, NamedKey::PageDown),               // KP_Page_Down
+    (0xff9c, NamedKey::End),                    // KP_End
+    (0xff9d, NamedKey::Clear),                  // KP_Begin
+    (0xff9e, NamedKey::Insert),                 // KP_Insert
+    (0xff9f, NamedKey::Delete),                 // KP_Delete
+    (0xffbe, NamedKey::F1),                     // F1
+    (0xffbf, NamedKey::F2),                     // F2
+    (0xffc0, NamedKey::F3),                     // F3
+    (0xffc1, NamedKey::F4),                     // F4
+    (0xffc2, NamedKey::F5),                     // F5
+    (0xffc3, NamedKey::F6),                     // F6
+    (0xffc4, NamedKey::F7),                     // F7
+    (0xffc5, NamedKey::F8),                     // F8
+    (0xffc6, NamedKey::F9),                     // F9
+    (0xffc7, NamedKey::F10),                    // F10
+    (0xffc8, NamedKey::F11),                    // F11
+    (0xffc9, NamedKey::F12),                    // F12
+    (0xffca, NamedKey::F13),                    // F13
+    (0xffcb, NamedKey::F14),                    // F14
+    (0xffcc, NamedKey::F15),                    // F15
+    (0xffcd, NamedKey::F16),                    // F16
+    (0xffce, NamedKey::F17),                    // F17
+    (0xffcf, NamedKey::F18),                    // F18
+    (0xffd0, NamedKey::F19),                    // F19
+    (0xffd1, NamedKey::F20),                    // F20
+    (0xffd2, NamedKey::F21),                    // F21
+    (0xffd3, NamedKey::F22),                    // F22
+    (0xffd4, NamedKey::F23),                    // F23
+    (0xffd5, NamedKey::F24),                    // F24
+    (0xffd6, NamedKey::F25),                    // F25
+    (0xffd7, NamedKey::F26),                    // F26
+    (0xffd8, NamedKey::F27),                    // F27
+    (0xffd9, NamedKey::F28),                    // F28
+    (0xffda, NamedKey::F29),                    // F29
+    (0xffdb, NamedKey::F30),                    // F30
+    (0xffdc, NamedKey::F31),                    // F31
+    (0xffdd, NamedKey::F32),                    // F32
+    (0xffde, NamedKey::F33),                    // F33
+    (0xffdf, NamedKey::F34),                    // F34
+    (0xffe0, NamedKey::F35),                    // F35
+    (0xffe1, NamedKey::Shift),                  // Shift_L
+    (0xffe2, NamedKey::Shift),                  // Shift_R
+    (0xffe3, NamedKey::Control),                // Control_L
+    (0xffe4, NamedKey::Control),                // Control_R
+    (0xffe5, NamedKey::CapsLock),               // Caps_Lock
+    (0xffe7, NamedKey::Meta),                   // Meta_L
+    (0xffe8, NamedKey::Meta),                   // Meta_R
+    (0xffe9, NamedKey::Alt),                    // Alt_L
+    (0xffea, NamedKey::Alt),                    // Alt_R
+    (0xffeb, NamedKey::Meta),                   // Super_L
+    (0xffec, NamedKey::Meta),                   // Super_R
+    (0xffed, NamedKey::Meta),                   // Hyper_L
+    (0xffee, NamedKey::Meta),                   // Hyper_R
+    (0xffff, NamedKey::Delete),                 // Delete
+    (0x1008ff02, NamedKey::BrightnessUp),       // XF86MonBrightnessUp
+    (0x1008ff03, NamedKey::BrightnessDown),     // XF86MonBrightnessDown
+    (0x1008ff11, NamedKey::AudioVolumeDown),    // XF86AudioLowerVolume
+    (0x1008ff12, NamedKey::AudioVolumeMute),    // XF86AudioMute
+    (0x1008ff13, NamedKey::AudioVolumeUp),      // XF86AudioRaiseVolume
+    (0x1008ff14, NamedKey::MediaPlay),          // XF86AudioPlay
+    (0x1008ff15, NamedKey::MediaStop),          // XF86AudioStop
+    (0x1008ff16, NamedKey::MediaTrackPrevious), // XF86AudioPrev
+    (0x1008ff17, NamedKey::MediaTrackNext),     // XF86AudioNext
+    (0x1008ff18, NamedKey::BrowserHome),        // XF86HomePage
+    (0x1008ff19, NamedKey::LaunchMail),         // XF86Mail
+    (0x1008ff1b, NamedKey::BrowserSearch),      // XF86Search
+    (0x1008ff26, NamedKey::BrowserBack),        // XF86Back
+    (0x1008ff27, NamedKey::BrowserForward),     // XF86Forward
+    (0x1008ff28, NamedKey::BrowserStop),        // XF86Stop
+    (0x1008ff29, NamedKey::BrowserRefresh),     // XF86Refresh
+    (0x1008ff2c, NamedKey::Eject),              // XF86Eject
+    (0x1008ff30, NamedKey::BrowserFavorites),   // XF86Favorites
+    (0x1008ff31, NamedKey::MediaPause),         // XF86AudioPause
+];
+
+impl NamedKey {
+    /// Get the named key for a GDK key value (`GDK_KEY_*`).
+    ///
+    /// Returns `None` for key values of characters and for key values
+    /// without a corresponding [`NamedKey`]. The legacy <kbd>Super</kbd>
+    /// and <kbd>Hyper</kbd> keys are mapped to [`NamedKey::Meta`].
+    pub fn from_gdk_keyval(keyval: u32) -> Option<NamedKey> {
+        GDK_KEYVALS
+            .iter()
+            .find(|&&(k, _)| k == keyval)
+            .map(|&(_, key)| key)
+    }
+}
+
+impl Code {
+    /// Get the code for a GDK hardware key code.
+    ///
+    /// On X11 and Wayland the hardware key code is the xkb key code, see
+    /// [`Code::from_xkb_keycode`]. Other GDK backends use platform specific
+    /// key codes that are not supported.
+    pub fn from_gdk_hardware_keycode(keycode: u32) -> Option<Code> {
+        Code::from_xkb_keycode(keycode)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn gdk_keyval() {
+        assert_eq!(NamedKey::from_gdk_keyval(0xff0d), Some(NamedKey::Enter));
+        assert_eq!(NamedKey::from_gdk_keyval(0xff1b), Some(NamedKey::Escape));
+        assert_eq!(NamedKey::from_gdk_keyval(0xffeb), Some(NamedKey::Meta));
+        assert_eq!(NamedKey::from_gdk_keyval(0xffe0), Some(NamedKey::F35));
+        assert_eq!(NamedKey::from_gdk_keyval(0x0061), None);
+    }
+
+    #[test]
+    fn gdk_hardware_keycode() {
+        assert_eq!(Code::from_gdk_hardware_keycode(38), Some(Code::KeyA));
+        assert_eq!(Code::from_gdk_hardware_keycode(0), None);
+    }
+}
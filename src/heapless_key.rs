@@ -0,0 +1,105 @@
+//! A key value that does not allocate, for the `heapless` feature.
+
+use heapless::String;
+
+use crate::{Key, NamedKey};
+
+/// A [`Key`] that stores its character value without heap allocation.
+///
+/// The character value holds at most `N` bytes of UTF-8. Converting a
+/// longer [`Key::Character`] truncates it to the longest prefix that fits,
+/// without splitting a codepoint. If nothing fits, the key becomes
+/// [`NamedKey::Unidentified`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum HeaplessKey<const N: usize> {
+    /// A key string that corresponds to the character typed by the user.
+    ///
+    /// See [`Key::Character`].
+    Character(String<N>),
+    /// A key that does not produce a character, e.g. <kbd>Enter</kbd>.
+    ///
+    /// See [`Key::Named`].
+    Named(NamedKey),
+}
+
+impl<const N: usize> HeaplessKey<N> {
+    /// Create a key from a character value, truncating it to `N` bytes.
+    ///
+    /// Returns [`NamedKey::Unidentified`] if not even the first codepoint
+    /// fits, as an empty character value is not a valid key.
+    pub fn from_character(s: &str) -> Self {
+        let mut end = s.len().min(N);
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        if end == 0 {
+            return HeaplessKey::Named(NamedKey::Unidentified);
+        }
+        let mut c = String::new();
+        // Cannot fail, the prefix is at most `N` bytes long.
+        let _ = c.push_str(&s[..end]);
+        HeaplessKey::Character(c)
+    }
+}
+
+impl<const N: usize> From<&Key> for HeaplessKey<N> {
+    /// Convert a key, truncating the character value to `N` bytes.
+    fn from(key: &Key) -> Self {
+        match key {
+            Key::Character(s) => HeaplessKey::from_character(s),
+            Key::Named(k) => HeaplessKey::Named(*k),
+        }
+    }
+}
+
+impl<const N: usize> From<Key> for HeaplessKey<N> {
+    /// Convert a key, truncating the character value to `N` bytes.
+    fn from(key: Key) -> Self {
+        HeaplessKey::from(&key)
+    }
+}
+
+impl<const N: usize> From<HeaplessKey<N>> for Key {
+    fn from(key: HeaplessKey<N>) -> Self {
+        match key {
+            HeaplessKey::Character(s) => Key::Character(s.as_str().into()),
+            HeaplessKey::Named(k) => Key::Named(k),
+        }
+    }
+}
+
+impl<const N: usize> From<NamedKey> for HeaplessKey<N> {
+    fn from(k: NamedKey) -> Self {
+        HeaplessKey::Named(k)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn short_character() {
+        let key = Key::Character("a".to_string());
+        let small: HeaplessKey<4> = key.clone().into();
+        assert_eq!(small, HeaplessKey::from_character("a"));
+        assert_eq!(Key::from(small), key);
+        let named: HeaplessKey<4> = Key::Named(NamedKey::Enter).into();
+        assert_eq!(named, HeaplessKey::Named(NamedKey::Enter));
+    }
+
+    #[test]
+    fn truncation() {
+        let small: HeaplessKey<4> = Key::Character("abcdef".to_string()).into();
+        assert_eq!(Key::from(small), Key::Character("abcd".to_string()));
+        // "é" is two bytes long and must not be split.
+        let small: HeaplessKey<4> = Key::Character("abcé".to_string()).into();
+        assert_eq!(Key::from(small), Key::Character("abc".to_string()));
+        // Nothing fits.
+        let small: HeaplessKey<2> = Key::Character("😀".to_string()).into();
+        assert_eq!(small, HeaplessKey::Named(NamedKey::Unidentified));
+        let empty: HeaplessKey<0> = Key::Character("a".to_string()).into();
+        assert_eq!(Key::from(empty), Key::Named(NamedKey::Unidentified));
+    }
+}
@@ -0,0 +1,324 @@
+//! Conversions from USB HID usages.
+//!
+//! Specification: <https://usb.org/document-library/hid-usage-tables-15>
+
+use crate::Code;
+
+impl Code {
+    /// Get the code for a usage on the Keyboard/Keypad usage page (0x07).
+    ///
+    /// Returns `None` if the usage has no corresponding [`Code`], this
+    /// includes the error usages 0x01 to 0x03 reported on key rollover.
+    pub fn from_hid_keyboard_usage(usage: u16) -> Option<Code> {
+        Some(match usage {
+            0x0004 => Code::KeyA,
+            0x0005 => Code::KeyB,
+            0x0006 => Code::KeyC,
+            0x0007 => Code::KeyD,
+            0x0008 => Code::KeyE,
+            0x0009 => Code::KeyF,
+            0x000A => Code::KeyG,
+            0x000B => Code::KeyH,
+            0x000C => Code::KeyI,
+            0x000D => Code::KeyJ,
+            0x000E => Code::KeyK,
+            0x000F => Code::KeyL,
+            0x0010 => Code::KeyM,
+            0x0011 => Code::KeyN,
+            0x0012 => Code::KeyO,
+            0x0013 => Code::KeyP,
+            0x0014 => Code::KeyQ,
+            0x0015 => Code::KeyR,
+            0x0016 => Code::KeyS,
+            0x0017 => Code::KeyT,
+            0x0018 => Code::KeyU,
+            0x0019 => Code::KeyV,
+            0x001A => Code::KeyW,
+            0x001B => Code::KeyX,
+            0x001C => Code::KeyY,
+            0x001D => Code::KeyZ,
+            0x001E => Code::Digit1,
+            0x001F => Code::Digit2,
+            0x0020 => Code::Digit3,
+            0x0021 => Code::Digit4,
+            0x0022 => Code::Digit5,
+            0x0023 => Code::Digit6,
+            0x0024 => Code::Digit7,
+            0x0025 => Code::Digit8,
+            0x0026 => Code::Digit9,
+            0x0027 => Code::Digit0,
+            0x0028 => Code::Enter,
+            0x0029 => Code::Escape,
+            0x002A => Code::Backspace,
+            0x002B => Code::Tab,
+            0x002C => Code::Space,
+            0x002D => Code::Minus,
+            0x002E => Code::Equal,
+            0x002F => Code::BracketLeft,
+            0x0030 => Code::BracketRight,
+            0x0031 | 0x0032 => Code::Backslash,
+            0x0033 => Code::Semicolon,
+            0x0034 => Code::Quote,
+            0x0035 => Code::Backquote,
+            0x0036 => Code::Comma,
+            0x0037 => Code::Period,
+            0x0038 => Code::Slash,
+            0x0039 => Code::CapsLock,
+            0x003A => Code::F1,
+            0x003B => Code::F2,
+            0x003C => Code::F3,
+            0x003D => Code::F4,
+            0x003E => Code::F5,
+            0x003F => Code::F6,
+            0x0040 => Code::F7,
+            0x0041 => Code::F8,
+            0x0042 => Code::F9,
+            0x0043 => Code::F10,
+            0x0044 => Code::F11,
+            0x0045 => Code::F12,
+            0x0046 => Code::PrintScreen,
+            0x0047 => Code::ScrollLock,
+            0x0048 => Code::Pause,
+            0x0049 => Code::Insert,
+            0x004A => Code::Home,
+            0x004B => Code::PageUp,
+            0x004C => Code::Delete,
+            0x004D => Code::End,
+            0x004E => Code::PageDown,
+            0x004F => Code::ArrowRight,
+            0x0050 => Code::ArrowLeft,
+            0x0051 => Code::ArrowDown,
+            0x0052 => Code::ArrowUp,
+            0x0053 => Code::NumLock,
+            0x0054 => Code::NumpadDivide,
+            0x0055 => Code::NumpadMultiply,
+            0x0056 => Code::NumpadSubtract,
+            0x0057 => Code::NumpadAdd,
+            0x0058 => Code::NumpadEnter,
+            0x0059 => Code::Numpad1,
+            0x005A => Code::Numpad2,
+            0x005B => Code::Numpad3,
+            0x005C => Code::Numpad4,
+            0x005D => Code::Numpad5,
+            0x005E => Code::Numpad6,
+            0x005F => Code::Numpad7,
+            0x0060 => Code::Numpad8,
+            0x0061 => Code::Numpad9,
+            0x0062 => Code::Numpad0,
+            0x0063 => Code::NumpadDecimal,
+            0x0064 => Code::IntlBackslash,
+            0x0065 => Code::ContextMenu,
+            0x0066 => Code::Power,
+            0x0067 => Code::NumpadEqual,
+            0x0068 => Code::F13,
+            0x0069 => Code::F14,
+            0x006A => Code::F15,
+            0x006B => Code::F16,
+            0x006C => Code::F17,
+            0x006D => Code::F18,
+            0x006E => Code::F19,
+            0x006F => Code::F20,
+            0x0070 => Code::F21,
+            0x0071 => Code::F22,
+            0x0072 => Code::F23,
+            0x0073 => Code::F24,
+            0x0074 => Code::Open,
+            0x0075 => Code::Help,
+            0x0076 => Code::Props,
+            0x0077 => Code::Select,
+            0x0079 => Code::Again,
+            0x007A => Code::Undo,
+            0x007B => Code::Cut,
+            0x007C => Code::Copy,
+            0x007D => Code::Paste,
+            0x007E => Code::Find,
+            0x007F => Code::AudioVolumeMute,
+            0x0080 => Code::AudioVolumeUp,
+            0x0081 => Code::AudioVolumeDown,
+            0x0085 => Code::NumpadComma,
+            0x0087 => Code::IntlRo,
+            0x0088 => Code::KanaMode,
+            0x0089 => Code::IntlYen,
+            0x008A => Code::Convert,
+            0x008B => Code::NonConvert,
+            0x0090 => Code::Lang1,
+            0x0091 => Code::Lang2,
+            0x0092 => Code::Lang3,
+            0x0093 => Code::Lang4,
+            0x0094 => Code::Lang5,
+            0x009B => Code::Abort,
+            0x00B6 => Code::NumpadParenLeft,
+            0x00B7 => Code::NumpadParenRight,
+            0x00BB => Code::NumpadBackspace,
+            0x00D0 => Code::NumpadMemoryStore,
+            0x00D1 => Code::NumpadMemoryRecall,
+            0x00D2 => Code::NumpadMemoryClear,
+            0x00D3 => Code::NumpadMemoryAdd,
+            0x00D4 => Code::NumpadMemorySubtract,
+            0x00D8 => Code::NumpadClear,
+            0x00D9 => Code::NumpadClearEntry,
+            0x00E0 => Code::ControlLeft,
+            0x00E1 => Code::ShiftLeft,
+            0x00E2 => Code::AltLeft,
+            0x00E3 => Code::MetaLeft,
+            0x00E4 => Code::ControlRight,
+            0x00E5 => Code::ShiftRight,
+            0x00E6 => Code::AltRight,
+            0x00E7 => Code::MetaRight,
+            _ => return None,
+        })
+    }
+
+    /// Decode the pressed keys of a HID boot protocol keyboard report.
+    ///
+    /// The report consists of a modifier byte, a reserved byte and up to
+    /// six key usages. The returned iterator first yields the codes for the
+    /// modifier bits, from left <kbd>Control</kbd> to right <kbd>Meta</kbd>,
+    /// followed by the codes of the pressed keys. Unassigned usages are skipped.
+    pub fn from_hid_report(report: &[u8; 8]) -> HidReportKeys {
+        HidReportKeys {
+            report: *report,
+            index: 0,
+        }
+    }
+
+    /// Get the code for a usage on the Consumer usage page (0x0C).
+    ///
+    /// Media, browser and application launch keys are reported on this page
+    /// instead of the Keyboard/Keypad page (0x07).
+    ///
+    /// Returns `None` if the usage has no corresponding [`Code`].
+    pub fn from_hid_consumer_usage(usage: u16) -> Option<Code> {
+        Some(match usage {
+            0x0030 => Code::Power,
+            0x0032 => Code::Sleep,
+            0x006F => Code::BrightnessUp,
+            0x0070 => Code::BrightnessDown,
+            0x007C => Code::KeyboardBacklightToggle,
+            0x00B0 => Code::MediaPlay,
+            0x00B1 => Code::MediaPause,
+            0x00B2 => Code::MediaRecord,
+            0x00B3 => Code::MediaFastForward,
+            0x00B4 => Code::MediaRewind,
+            0x00B5 => Code::MediaTrackNext,
+            0x00B6 => Code::MediaTrackPrevious,
+            0x00B7 => Code::MediaStop,
+            0x00B8 => Code::Eject,
+            0x00CD => Code::MediaPlayPause,
+            0x00E2 => Code::AudioVolumeMute,
+            0x00E9 => Code::AudioVolumeUp,
+            0x00EA => Code::AudioVolumeDown,
+            0x0183 => Code::MediaSelect,
+            0x018A => Code::LaunchMail,
+            0x0192 => Code::LaunchApp2,
+            0x0194 => Code::LaunchApp1,
+            0x019F => Code::LaunchControlPanel,
+            0x01A2 => Code::SelectTask,
+            0x01AE => Code::KeyboardLayoutSelect,
+            0x01B1 => Code::LaunchScreenSaver,
+            0x01CB => Code::LaunchAssistant,
+            0x0202 => Code::Open,
+            0x0209 => Code::Props,
+            0x021A => Code::Undo,
+            0x021B => Code::Copy,
+            0x021C => Code::Cut,
+            0x021D => Code::Paste,
+            0x021F => Code::Find,
+            0x0221 => Code::BrowserSearch,
+            0x0223 => Code::BrowserHome,
+            0x0224 => Code::BrowserBack,
+            0x0225 => Code::BrowserForward,
+            0x0226 => Code::BrowserStop,
+            0x0227 => Code::BrowserRefresh,
+            0x022A => Code::BrowserFavorites,
+            0x0232 => Code::ZoomToggle,
+            0x0289 => Code::MailReply,
+            0x028B => Code::MailForward,
+            0x028C => Code::MailSend,
+            0x029F => Code::ShowAllWindows,
+            _ => return None,
+        })
+    }
+}
+
+/// Iterator over the codes of the keys pressed in a HID keyboard report.
+///
+/// Created with [`Code::from_hid_report`].
+#[derive(Clone, Debug)]
+pub struct HidReportKeys {
+    report: [u8; 8],
+    index: usize,
+}
+
+impl Iterator for HidReportKeys {
+    type Item = Code;
+
+    fn next(&mut self) -> Option<Code> {
+        // Indices 0 to 7 are the modifier bits,
+        // 8 to 13 the key usages in bytes 2 to 7.
+        while self.index < 14 {
+            let index = self.index;
+            self.index += 1;
+            let usage = if index < 8 {
+                if self.report[0] & (1 << index) == 0 {
+                    continue;
+                }
+                0xE0 + index as u16
+            } else {
+                self.report[index - 6] as u16
+            };
+            if let Some(code) = Code::from_hid_keyboard_usage(usage) {
+                return Some(code);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn keyboard_usage() {
+        assert_eq!(Code::from_hid_keyboard_usage(0x04), Some(Code::KeyA));
+        assert_eq!(Code::from_hid_keyboard_usage(0x27), Some(Code::Digit0));
+        assert_eq!(Code::from_hid_keyboard_usage(0xE7), Some(Code::MetaRight));
+        assert_eq!(Code::from_hid_keyboard_usage(0x01), None);
+    }
+
+    #[test]
+    fn report() {
+        // Left Shift + A + B.
+        let report = [0x02, 0x00, 0x04, 0x05, 0x00, 0x00, 0x00, 0x00];
+        let codes: Vec<Code> = Code::from_hid_report(&report).collect();
+        assert_eq!(codes, [Code::ShiftLeft, Code::KeyA, Code::KeyB]);
+    }
+
+    #[test]
+    fn consumer_usage() {
+        assert_eq!(
+            Code::from_hid_consumer_usage(0xCD),
+            Some(Code::MediaPlayPause)
+        );
+        assert_eq!(
+            Code::from_hid_consumer_usage(0xB5),
+            Some(Code::MediaTrackNext)
+        );
+        assert_eq!(
+            Code::from_hid_consumer_usage(0xE9),
+            Some(Code::AudioVolumeUp)
+        );
+        assert_eq!(
+            Code::from_hid_consumer_usage(0x0224),
+            Some(Code::BrowserBack)
+        );
+        assert_eq!(
+            Code::from_hid_consumer_usage(0x7C),
+            Some(Code::KeyboardBacklightToggle)
+        );
+        assert_eq!(Code::from_hid_consumer_usage(0x79), None);
+        assert_eq!(Code::from_hid_consumer_usage(0x0001), None);
+    }
+}
@@ -1,15 +1,24 @@
+use alloc::boxed::Box;
 use alloc::string::{String, ToString};
+#[cfg(not(feature = "std"))]
+use core::error::Error;
 use core::fmt;
 use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::error::Error;
 
 use crate::{first_char, NamedKey};
 
+#[cfg(feature = "segmentation")]
+use unicode_segmentation::UnicodeSegmentation;
+
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 /// The value received from the keypress.
 #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum Key {
     /// A key string that corresponds to the character typed by the user,
     /// taking into account the user’s current locale setting, modifier state,
@@ -18,6 +27,29 @@ pub enum Key {
     Named(NamedKey),
 }
 
+/// Deserialize a key, rejecting character values that are not valid key strings.
+///
+/// Uses the same format as the derived `Serialize` implementation.
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Key {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename = "Key")]
+        enum KeyRepr {
+            Character(String),
+            Named(NamedKey),
+        }
+
+        match KeyRepr::deserialize(deserializer)? {
+            KeyRepr::Character(s) if !is_key_string(&s) => Err(serde::de::Error::custom(
+                format_args!("invalid key string {:?}", s),
+            )),
+            KeyRepr::Character(s) => Ok(Key::Character(s)),
+            KeyRepr::Named(k) => Ok(Key::Named(k)),
+        }
+    }
+}
+
 impl fmt::Display for Key {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -29,29 +61,205 @@ impl fmt::Display for Key {
 
 /// Parse from string error, returned when string does not match to any [`Key`] variant.
 #[derive(Clone, Debug)]
-pub struct UnrecognizedKeyError;
+pub struct UnrecognizedKeyError {
+    input: Box<str>,
+}
+
+impl UnrecognizedKeyError {
+    /// The string that failed to parse.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl fmt::Display for UnrecognizedKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Unrecognized key: {:?}", self.input)
+    }
+}
+
+impl Error for UnrecognizedKeyError {}
 
+/// Parse a key from a character value or the name of a [`NamedKey`].
+///
+/// The empty string is parsed as [`NamedKey::Unidentified`], as some
+/// platforms report an empty key value for keys they cannot identify.
 impl FromStr for Key {
     type Err = UnrecognizedKeyError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if is_key_string(s) {
+        if s.is_empty() {
+            Ok(Self::Named(NamedKey::Unidentified))
+        } else if is_key_string(s) {
             Ok(Self::Character(s.to_string()))
         } else {
             Ok(Self::Named(
-                NamedKey::from_str(s).map_err(|_| UnrecognizedKeyError)?,
+                NamedKey::from_str(s).map_err(|_| UnrecognizedKeyError { input: s.into() })?,
             ))
         }
     }
 }
 
+impl TryFrom<&str> for Key {
+    type Error = UnrecognizedKeyError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Key::from_str(s)
+    }
+}
+
 impl From<NamedKey> for Key {
     fn from(value: NamedKey) -> Self {
         Self::Named(value)
     }
 }
 
+/// Create a [`Key::Character`] from any character.
+///
+/// Control characters are not special cased, so `'\n'` becomes
+/// `Key::Character("\n")` and not [`NamedKey::Enter`].
+impl From<char> for Key {
+    fn from(value: char) -> Self {
+        Self::Character(value.to_string())
+    }
+}
+
 impl Key {
+    /// Classify a string from a text input event, never failing.
+    ///
+    /// Like [`FromStr`] a string that is a valid character value becomes a
+    /// [`Key::Character`], otherwise it is parsed as a [`NamedKey`]. Strings
+    /// that are neither become [`NamedKey::Unidentified`] instead of an error.
+    pub fn from_text(s: &str) -> Key {
+        Key::from_str(s).unwrap_or(Key::Named(NamedKey::Unidentified))
+    }
+
+    /// Return `true` if the key is a [`Key::Named`] contained in `keys`.
+    ///
+    /// ```rust
+    /// # use keyboard_types::{Key, NamedKey};
+    /// const ARROWS: &[NamedKey] = &[
+    ///     NamedKey::ArrowLeft,
+    ///     NamedKey::ArrowUp,
+    ///     NamedKey::ArrowRight,
+    ///     NamedKey::ArrowDown,
+    /// ];
+    /// assert!(Key::Named(NamedKey::ArrowUp).is_one_of_named(ARROWS));
+    /// ```
+    pub fn is_one_of_named(&self, keys: &[NamedKey]) -> bool {
+        match self {
+            Key::Named(named) => keys.contains(named),
+            Key::Character(_) => false,
+        }
+    }
+
+    /// Iterate over the codepoints of a key with a character value.
+    ///
+    /// A [`Key::Character`] may contain more than one codepoint, for example
+    /// when it was produced by an IME. For [`Key::Named`] the iterator is empty.
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        match self {
+            Key::Character(ref c) => c.chars(),
+            Key::Named(_) => "".chars(),
+        }
+    }
+
+    /// Take the character value out of the key.
+    ///
+    /// Returns `None` for [`Key::Named`].
+    pub fn into_string(self) -> Option<String> {
+        match self {
+            Key::Character(c) => Some(c),
+            Key::Named(_) => None,
+        }
+    }
+
+    /// Convert `\r\n` and lone `\r` in a character value to `\n`.
+    ///
+    /// [`Key::Named`] values, including [`NamedKey::Enter`], are untouched.
+    pub fn normalize_newlines(&mut self) {
+        if let Key::Character(ref mut c) = self {
+            if c.contains('\r') {
+                *c = c.replace("\r\n", "\n").replace('\r', "\n");
+            }
+        }
+    }
+
+    /// Fold full-width ASCII characters in a character value to their
+    /// half-width equivalents.
+    ///
+    /// The full-width forms U+FF01 to U+FF5E (`！` to `～`) are replaced by
+    /// U+0021 to U+007E (`!` to `~`) and the ideographic space U+3000 by
+    /// U+0020. Other characters and [`Key::Named`] values are untouched.
+    pub fn normalize_width(&mut self) {
+        if let Key::Character(ref mut c) = self {
+            if c.chars()
+                .any(|c| matches!(c, '\u{3000}' | '\u{FF01}'..='\u{FF5E}'))
+            {
+                *c = c
+                    .chars()
+                    .map(|c| match c {
+                        '\u{3000}' => ' ',
+                        '\u{FF01}'..='\u{FF5E}' => {
+                            char::from_u32(c as u32 - 0xFF01 + 0x21).unwrap()
+                        }
+                        _ => c,
+                    })
+                    .collect();
+            }
+        }
+    }
+
+    /// Shorten a character value to at most `max_chars` codepoints.
+    ///
+    /// The value is never split inside a codepoint, but combining sequences
+    /// may be split. [`Key::Named`] values are untouched.
+    pub fn truncate_chars(&mut self, max_chars: usize) {
+        if let Key::Character(ref mut c) = self {
+            if let Some((index, _)) = c.char_indices().nth(max_chars) {
+                c.truncate(index);
+            }
+        }
+    }
+
+    /// Return `true` if the key has a character value consisting only of
+    /// characters from the Private Use Area U+E000 to U+F8FF.
+    ///
+    /// Some terminal key protocols encode keys as such characters, which are
+    /// not meant to be inserted as text.
+    pub fn is_private_use(&self) -> bool {
+        match self {
+            Key::Character(ref c) => {
+                !c.is_empty() && c.chars().all(|c| matches!(c, '\u{E000}'..='\u{F8FF}'))
+            }
+            Key::Named(_) => false,
+        }
+    }
+
+    /// Replace a character value consisting only of Private Use Area
+    /// characters with [`NamedKey::Unidentified`].
+    ///
+    /// Other keys are untouched, see [`is_private_use`](Self::is_private_use).
+    pub fn strip_private_use(&mut self) {
+        if self.is_private_use() {
+            *self = Key::Named(NamedKey::Unidentified);
+        }
+    }
+
+    /// Return the last user-perceived character (grapheme cluster) of a key
+    /// with a character value.
+    ///
+    /// Returns `None` for [`Key::Named`] and empty character values.
+    ///
+    /// Requires the `segmentation` feature.
+    #[cfg(feature = "segmentation")]
+    pub fn last_grapheme(&self) -> Option<&str> {
+        match self {
+            Key::Character(ref c) => c.graphemes(true).next_back(),
+            Key::Named(_) => None,
+        }
+    }
+
     /// Determine a *charCode* value for a key with a character value.
     ///
     /// For all other keys the value is zero.
@@ -125,24 +333,226 @@ impl Default for Key {
 /// Check if string can be used as a `Key::Character` _keystring_.
 ///
 /// This check is simple and is meant to prevents common mistakes like mistyped keynames
-/// (e.g. `Ennter`) from being recognized as characters.
+/// (e.g. `Ennter`) from being recognized as characters. The empty string is
+/// not a key string.
 fn is_key_string(s: &str) -> bool {
-    s.chars().all(|c| !c.is_control()) && s.chars().skip(1).all(|c| !c.is_ascii())
+    !s.is_empty() && s.chars().all(|c| !c.is_control()) && s.chars().skip(1).all(|c| !c.is_ascii())
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::Code;
 
     #[test]
     fn test_is_key_string() {
         assert!(is_key_string("A"));
         assert!(!is_key_string("AA"));
         assert!(!is_key_string("	"));
+        assert!(!is_key_string(""));
+    }
+
+    #[test]
+    fn from_empty_str() {
+        assert_eq!(
+            Key::from_str("").ok(),
+            Some(Key::Named(NamedKey::Unidentified))
+        );
+        assert_eq!(Key::from_text(""), Key::Named(NamedKey::Unidentified));
+    }
+
+    #[test]
+    fn from_text() {
+        assert_eq!(Key::from_text("a"), Key::Character("a".to_string()));
+        assert_eq!(Key::from_text("Enter"), Key::Named(NamedKey::Enter));
+        assert_eq!(Key::from_text("Ennter"), Key::Named(NamedKey::Unidentified));
+        assert_eq!(Key::from_text("\u{7}"), Key::Named(NamedKey::Unidentified));
+    }
+
+    #[test]
+    fn from_char() {
+        assert_eq!(Key::from('a'), Key::Character("a".to_string()));
+        assert_eq!(Key::from('\n'), Key::Character("\n".to_string()));
+    }
+
+    #[test]
+    fn truncate_chars() {
+        let mut key = Key::Character("äöü".to_string());
+        key.truncate_chars(1);
+        assert_eq!(key, Key::Character("ä".to_string()));
+        key.truncate_chars(5);
+        assert_eq!(key, Key::Character("ä".to_string()));
+        let mut key = Key::Named(NamedKey::Enter);
+        key.truncate_chars(0);
+        assert_eq!(key, Key::Named(NamedKey::Enter));
+    }
+
+    #[test]
+    fn private_use() {
+        let mut key = Key::Character("\u{E000}\u{F8FF}".to_string());
+        assert!(key.is_private_use());
+        key.strip_private_use();
+        assert_eq!(key, Key::Named(NamedKey::Unidentified));
+
+        let mut key = Key::Character("a\u{E000}".to_string());
+        assert!(!key.is_private_use());
+        key.strip_private_use();
+        assert_eq!(key, Key::Character("a\u{E000}".to_string()));
+
+        assert!(!Key::Character(String::new()).is_private_use());
+        assert!(!Key::Named(NamedKey::Enter).is_private_use());
+    }
+
+    #[test]
+    fn is_one_of_named() {
+        let arrows = [
+            NamedKey::ArrowLeft,
+            NamedKey::ArrowUp,
+            NamedKey::ArrowRight,
+            NamedKey::ArrowDown,
+        ];
+        assert!(Key::Named(NamedKey::ArrowLeft).is_one_of_named(&arrows));
+        assert!(!Key::Named(NamedKey::Enter).is_one_of_named(&arrows));
+        assert!(!Key::Character("a".to_string()).is_one_of_named(&arrows));
+        assert!(!Key::Named(NamedKey::ArrowLeft).is_one_of_named(&[]));
+    }
+
+    #[test]
+    fn try_from_str() {
+        assert_eq!(
+            Key::try_from("a").ok(),
+            Some(Key::Character("a".to_string()))
+        );
+        assert_eq!(
+            Key::try_from("Enter").ok(),
+            Some(Key::Named(NamedKey::Enter))
+        );
+        assert_eq!(NamedKey::try_from("Enter").ok(), Some(NamedKey::Enter));
+        assert_eq!(Code::try_from("KeyA").ok(), Some(Code::KeyA));
+        assert!(Key::try_from("Ennter").is_err());
+        assert!(NamedKey::try_from("a").is_err());
+        assert!(Code::try_from("KyeA").is_err());
+    }
+
+    #[test]
+    fn errors() {
+        use crate::Code;
+
+        let key_err = "Ennter".parse::<Key>().unwrap_err();
+        let named_key_err = "Ennter".parse::<NamedKey>().unwrap_err();
+        let code_err = "KyeA".parse::<Code>().unwrap_err();
+        assert_eq!(key_err.input(), "Ennter");
+        assert_eq!(key_err.to_string(), "Unrecognized key: \"Ennter\"");
+        assert_eq!(named_key_err.to_string(), "Unrecognized key: \"Ennter\"");
+        assert_eq!(code_err.input(), "KyeA");
+        assert_eq!(code_err.to_string(), "Unrecognized code: \"KyeA\"");
+
+        let errors: [&dyn Error; 3] = [&key_err, &named_key_err, &code_err];
+        assert!(errors.iter().all(|e| e.source().is_none()));
+    }
+
+    #[test]
+    fn editing_named_keys() {
+        let keys = [
+            NamedKey::ContextMenu,
+            NamedKey::Props,
+            NamedKey::Again,
+            NamedKey::Undo,
+            NamedKey::Redo,
+            NamedKey::Cut,
+            NamedKey::Copy,
+            NamedKey::Paste,
+            NamedKey::Find,
+            NamedKey::Open,
+            NamedKey::Close,
+            NamedKey::Save,
+        ];
+        for key in keys {
+            assert_eq!(NamedKey::from_str(key.to_string().as_str()).ok(), Some(key));
+            assert!(NamedKey::all().contains(&key));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialize() {
+        let key: Key = serde_json::from_str(r#"{"Character":"a"}"#).unwrap();
+        assert_eq!(key, Key::Character("a".to_string()));
+        let key: Key = serde_json::from_str(r#"{"Named":"Enter"}"#).unwrap();
+        assert_eq!(key, Key::Named(NamedKey::Enter));
+        assert_eq!(
+            serde_json::to_string(&Key::Character("a".to_string())).unwrap(),
+            r#"{"Character":"a"}"#
+        );
+        assert!(serde_json::from_str::<Key>(r#"{"Character":"\u0007"}"#).is_err());
+        assert!(serde_json::from_str::<Key>(r#"{"Character":""}"#).is_err());
+    }
+
+    #[test]
+    fn unidentified() {
+        use crate::Code;
+
+        assert_eq!(
+            Code::from_str("Unidentified").ok(),
+            Some(Code::Unidentified)
+        );
+        assert_eq!(
+            NamedKey::from_str("Unidentified").ok(),
+            Some(NamedKey::Unidentified)
+        );
+        assert_eq!(Key::from_str("Unidentified").ok(), Some(Key::default()));
     }
 
     #[test]
     fn into() {
         assert_eq!(Key::Named(NamedKey::Enter), NamedKey::Enter.into());
     }
+
+    #[test]
+    fn chars() {
+        let key = Key::Character("A\u{0308}".to_string());
+        assert!(key.chars().eq(['A', '\u{0308}']));
+        assert_eq!(Key::Named(NamedKey::Enter).chars().next(), None);
+    }
+
+    #[test]
+    fn normalize_newlines() {
+        let mut key = Key::Character("\r\n".to_string());
+        key.normalize_newlines();
+        assert_eq!(key, Key::Character("\n".to_string()));
+        let mut key = Key::Character("\r\r\n".to_string());
+        key.normalize_newlines();
+        assert_eq!(key, Key::Character("\n\n".to_string()));
+        let mut key = Key::Named(NamedKey::Enter);
+        key.normalize_newlines();
+        assert_eq!(key, Key::Named(NamedKey::Enter));
+    }
+
+    #[test]
+    fn normalize_width() {
+        let mut key = Key::Character("\u{FF21}".to_string());
+        key.normalize_width();
+        assert_eq!(key, Key::Character("A".to_string()));
+        let mut key = Key::Character("\u{FF01}\u{3000}\u{FF5E}あ".to_string());
+        key.normalize_width();
+        assert_eq!(key, Key::Character("! ~あ".to_string()));
+    }
+
+    #[test]
+    fn into_string() {
+        let text = "ä".to_string();
+        let ptr = text.as_ptr();
+        let text = Key::Character(text).into_string().unwrap();
+        assert_eq!(text, "ä");
+        assert_eq!(text.as_ptr(), ptr);
+        assert_eq!(Key::Named(NamedKey::Enter).into_string(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "segmentation")]
+    fn last_grapheme() {
+        let key = Key::Character("e\u{0301}a\u{0308}".to_string());
+        assert_eq!(key.last_grapheme(), Some("a\u{0308}"));
+        assert_eq!(Key::Named(NamedKey::Enter).last_grapheme(), None);
+    }
 }
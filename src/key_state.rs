@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 /// Describes the state a key is in.
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum KeyState {
     /// The key is pressed down.
     ///
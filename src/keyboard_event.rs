@@ -1,9 +1,18 @@
+//! Keyboard events and their serialization.
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::{Code, Key, KeyState, Location, Modifiers};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{Code, Key, KeyState, Location, Modifiers, NamedKey};
 
 /// Keyboard events are issued for all pressed and released keys.
+///
+/// Use [`dom_names`] to serialize the fields in camel case like the DOM
+/// `KeyboardEvent`.
 #[derive(Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct KeyboardEvent {
@@ -14,14 +23,134 @@ pub struct KeyboardEvent {
     /// Physical key position.
     pub code: Code,
     /// Location for keys with multiple instances on common keyboards.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_default"))]
     pub location: Location,
     /// Flags for pressed modifier keys.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_default"))]
     pub modifiers: Modifiers,
     /// True if the key is currently auto-repeated.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_default"))]
     pub repeat: bool,
     /// Events with this flag should be ignored in a text editor
     /// and instead [composition events](crate::CompositionEvent) should be used.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_default"))]
     pub is_composing: bool,
+    /// Time of the event in milliseconds, if known.
+    ///
+    /// The value is provided by the caller and should come from a monotonic
+    /// clock. Only differences between timestamps are meaningful.
+    ///
+    /// The timestamp takes part in the derived comparisons and hashing, so
+    /// the same key event at two different times is not equal. Clear it
+    /// before comparing events by content.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub timestamp: Option<u64>,
+}
+
+/// Used to omit fields with default values when serializing.
+#[cfg(feature = "serde")]
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+/// Serialize a [`KeyboardEvent`] with the camel case field names of the DOM,
+/// e.g. `isComposing` instead of `is_composing`.
+///
+/// Use it with `#[serde(with = "keyboard_types::keyboard_event::dom_names")]`.
+/// The values are serialized like the default format, only the field names
+/// differ.
+///
+/// ```rust
+/// use keyboard_types::{Code, KeyboardEvent, NamedKey};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Message {
+///     #[serde(with = "keyboard_types::keyboard_event::dom_names")]
+///     event: KeyboardEvent,
+/// }
+///
+/// let event = KeyboardEvent {
+///     is_composing: true,
+///     ..KeyboardEvent::key_down(NamedKey::Process, Code::KeyA)
+/// };
+/// let json = serde_json::to_string(&Message { event }).unwrap();
+/// assert!(json.contains(r#""isComposing":true"#));
+/// ```
+#[cfg(feature = "serde")]
+pub mod dom_names {
+    use super::{is_default, KeyboardEvent};
+    use crate::{Code, Key, KeyState, Location, Modifiers};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize)]
+    #[serde(rename = "KeyboardEvent", rename_all = "camelCase")]
+    struct DomKeyboardEventRef<'a> {
+        state: KeyState,
+        key: &'a Key,
+        code: Code,
+        #[serde(skip_serializing_if = "is_default")]
+        location: Location,
+        #[serde(skip_serializing_if = "is_default")]
+        modifiers: Modifiers,
+        #[serde(skip_serializing_if = "is_default")]
+        repeat: bool,
+        #[serde(skip_serializing_if = "is_default")]
+        is_composing: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        timestamp: Option<u64>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "KeyboardEvent", rename_all = "camelCase")]
+    struct DomKeyboardEvent {
+        state: KeyState,
+        key: Key,
+        code: Code,
+        #[serde(default)]
+        location: Location,
+        #[serde(default)]
+        modifiers: Modifiers,
+        #[serde(default)]
+        repeat: bool,
+        #[serde(default)]
+        is_composing: bool,
+        #[serde(default)]
+        timestamp: Option<u64>,
+    }
+
+    /// Serialize the event with camel case field names.
+    pub fn serialize<S: Serializer>(event: &KeyboardEvent, s: S) -> Result<S::Ok, S::Error> {
+        DomKeyboardEventRef {
+            state: event.state,
+            key: &event.key,
+            code: event.code,
+            location: event.location,
+            modifiers: event.modifiers,
+            repeat: event.repeat,
+            is_composing: event.is_composing,
+            timestamp: event.timestamp,
+        }
+        .serialize(s)
+    }
+
+    /// Deserialize the event from camel case field names.
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<KeyboardEvent, D::Error> {
+        let event = DomKeyboardEvent::deserialize(d)?;
+        Ok(KeyboardEvent {
+            state: event.state,
+            key: event.key,
+            code: event.code,
+            location: event.location,
+            modifiers: event.modifiers,
+            repeat: event.repeat,
+            is_composing: event.is_composing,
+            timestamp: event.timestamp,
+        })
+    }
 }
 
 impl KeyboardEvent {
@@ -46,4 +175,770 @@ impl KeyboardEvent {
             ..Default::default()
         }
     }
+
+    /// Create a builder for a keyboard event.
+    ///
+    /// All fields not set on the builder have the same value as in
+    /// [`KeyboardEvent::default`].
+    ///
+    /// ```rust
+    /// # use keyboard_types::{Code, Key, KeyState, KeyboardEvent, Location, Modifiers};
+    /// let event = KeyboardEvent::builder()
+    ///     .state(KeyState::Up)
+    ///     .key(Key::Character("A".to_owned()))
+    ///     .code(Code::KeyA)
+    ///     .location(Location::Standard)
+    ///     .modifiers(Modifiers::SHIFT)
+    ///     .repeat(false)
+    ///     .is_composing(false)
+    ///     .timestamp(1000)
+    ///     .build();
+    /// assert_eq!(
+    ///     event,
+    ///     KeyboardEvent {
+    ///         state: KeyState::Up,
+    ///         key: Key::Character("A".to_owned()),
+    ///         code: Code::KeyA,
+    ///         location: Location::Standard,
+    ///         modifiers: Modifiers::SHIFT,
+    ///         repeat: false,
+    ///         is_composing: false,
+    ///         timestamp: Some(1000),
+    ///     }
+    /// );
+    /// ```
+    pub fn builder() -> KeyboardEventBuilder {
+        KeyboardEventBuilder::default()
+    }
+
+    /// Return `true` if the event may trigger a keyboard shortcut.
+    ///
+    /// This is the case for key down events that are not part of a
+    /// composition session and whose key is not a modifier key like
+    /// <kbd>Shift</kbd> or <kbd>Control</kbd>.
+    ///
+    /// Auto-repeated events are rejected, so holding a shortcut triggers it
+    /// only once. Check the other conditions manually if a shortcut should
+    /// repeat, e.g. for scrolling.
+    pub fn is_shortcut_candidate(&self) -> bool {
+        self.state == KeyState::Down
+            && !self.repeat
+            && !self.is_composing
+            && !self.is_modifier_key()
+    }
+
+    /// Return `true` if the event is for a modifier key.
+    ///
+    /// This is the case if the key is a modifier like [`NamedKey::Shift`] or
+    /// [`NamedKey::CapsLock`], or if the code is one of [`Code::MODIFIERS`].
+    pub fn is_modifier_event(&self) -> bool {
+        self.is_modifier_key() || Code::MODIFIERS.contains(&self.code)
+    }
+
+    #[allow(deprecated)]
+    fn is_modifier_key(&self) -> bool {
+        matches!(
+            self.key,
+            Key::Named(
+                NamedKey::Alt
+                    | NamedKey::AltGraph
+                    | NamedKey::CapsLock
+                    | NamedKey::Control
+                    | NamedKey::Fn
+                    | NamedKey::FnLock
+                    | NamedKey::Meta
+                    | NamedKey::NumLock
+                    | NamedKey::ScrollLock
+                    | NamedKey::Shift
+                    | NamedKey::Symbol
+                    | NamedKey::SymbolLock
+                    | NamedKey::Hyper
+                    | NamedKey::Super
+            )
+        )
+    }
+
+    /// Get the key chord pressed by this event, e.g. to record a new key binding.
+    ///
+    /// Returns `None` unless the event is a
+    /// [shortcut candidate](Self::is_shortcut_candidate), so pressing only
+    /// modifier keys or releasing a key captures nothing.
+    pub fn captured_binding(&self) -> Option<(Modifiers, Key)> {
+        if self.is_shortcut_candidate() {
+            Some((self.modifiers, self.key.clone()))
+        } else {
+            None
+        }
+    }
+
+    /// Return a copy of the event with the opposite state and `repeat` cleared.
+    ///
+    /// This turns a key down event into the matching key up event and vice
+    /// versa, e.g. to synthesize the release of a key.
+    pub fn flip_state(&self) -> KeyboardEvent {
+        KeyboardEvent {
+            state: match self.state {
+                KeyState::Down => KeyState::Up,
+                KeyState::Up => KeyState::Down,
+            },
+            repeat: false,
+            ..self.clone()
+        }
+    }
+
+    /// Return a copy of the event with the typed text removed, for logging.
+    ///
+    /// A [`Key::Character`] value is replaced by `"•"`, so the logged event
+    /// does not reveal e.g. a password. Named keys and all other fields,
+    /// including the code, are kept.
+    pub fn redacted(&self) -> KeyboardEvent {
+        let key = match self.key {
+            Key::Character(_) => Key::Character(String::from("•")),
+            Key::Named(named) => Key::Named(named),
+        };
+        KeyboardEvent {
+            key,
+            ..self.clone()
+        }
+    }
+
+    /// Format the event tersely, e.g. for logging on embedded targets.
+    ///
+    /// The format is the [event type](KeyState::event_type), the code, the
+    /// key with character values in quotes, the location unless it is
+    /// [`Location::Standard`], the modifiers unless empty and the words
+    /// `repeat` and `composing` if the flags are set. The timestamp is
+    /// omitted. Unlike the derived [`Debug`] implementation only the string
+    /// names of the values are written, which needs less code.
+    ///
+    /// ```rust
+    /// # use keyboard_types::{Code, KeyboardEvent, Location, Modifiers};
+    /// let event = KeyboardEvent {
+    ///     location: Location::Left,
+    ///     modifiers: Modifiers::CONTROL,
+    ///     repeat: true,
+    ///     ..KeyboardEvent::key_down('a', Code::KeyA)
+    /// };
+    /// assert_eq!(
+    ///     event.compact_debug().to_string(),
+    ///     r#"keydown KeyA "a" Left Control repeat"#
+    /// );
+    /// ```
+    pub fn compact_debug(&self) -> impl fmt::Display + '_ {
+        CompactDebug(self)
+    }
+
+    /// Set the location from the code if no location was set.
+    ///
+    /// If `location` is [`Location::Standard`] it is replaced by the location
+    /// derived from `code` with [`Location::from_code`]. Other locations are kept.
+    pub fn infer_location(mut self) -> Self {
+        if self.location == Location::Standard {
+            self.location = Location::from_code(self.code);
+        }
+        self
+    }
+
+    /// Apply all normalizations to get a canonical event for storage and comparison.
+    ///
+    /// This is equivalent to calling [`normalize_alt_graph`](Self::normalize_alt_graph),
+    /// [`fold_numpad`](Self::fold_numpad) and [`fold_modifier_side`](Self::fold_modifier_side)
+    /// in that order.
+    pub fn canonicalize(&self) -> KeyboardEvent {
+        self.clone()
+            .normalize_alt_graph()
+            .fold_numpad()
+            .fold_modifier_side()
+    }
+
+    /// Remove the control and alt modifiers if the `AltGraph` modifier is active.
+    ///
+    /// Windows emulates <kbd>Alt Gr</kbd> by reporting <kbd>Control</kbd> and <kbd>Alt</kbd>
+    /// as pressed, while other platforms only report [`Modifiers::ALT_GRAPH`].
+    pub fn normalize_alt_graph(mut self) -> Self {
+        if self.modifiers.contains(Modifiers::ALT_GRAPH) {
+            self.modifiers.remove(Modifiers::CONTROL | Modifiers::ALT);
+        }
+        self
+    }
+
+    /// Replace numpad keys with the equivalent keys of the main keyboard area.
+    ///
+    /// Digits, <kbd>Enter</kbd> and the keys producing `.`, `,`, `-`, `/` and `=`
+    /// are replaced and their location is set to [`Location::Standard`].
+    /// Other keys are kept. A numpad key is only replaced if it produces a
+    /// character, or [`NamedKey::Enter`] for [`Code::NumpadEnter`], so the
+    /// navigation keys reported with <kbd>Num Lock</kbd> off stay on the numpad.
+    pub fn fold_numpad(mut self) -> Self {
+        let folds = match self.key {
+            Key::Character(_) => true,
+            Key::Named(key) => self.code == Code::NumpadEnter && key == NamedKey::Enter,
+        };
+        if !folds {
+            return self;
+        }
+        let code = match self.code {
+            Code::Numpad0 => Code::Digit0,
+            Code::Numpad1 => Code::Digit1,
+            Code::Numpad2 => Code::Digit2,
+            Code::Numpad3 => Code::Digit3,
+            Code::Numpad4 => Code::Digit4,
+            Code::Numpad5 => Code::Digit5,
+            Code::Numpad6 => Code::Digit6,
+            Code::Numpad7 => Code::Digit7,
+            Code::Numpad8 => Code::Digit8,
+            Code::Numpad9 => Code::Digit9,
+            Code::NumpadEnter => Code::Enter,
+            Code::NumpadDecimal => Code::Period,
+            Code::NumpadComma => Code::Comma,
+            Code::NumpadSubtract => Code::Minus,
+            Code::NumpadDivide => Code::Slash,
+            Code::NumpadEqual => Code::Equal,
+            _ => return self,
+        };
+        self.code = code;
+        self.location = Location::Standard;
+        self
+    }
+
+    /// Replace right modifier keys with their left counterpart.
+    ///
+    /// The code and location of the right <kbd>Alt</kbd>, <kbd>Control</kbd>,
+    /// <kbd>Meta</kbd> and <kbd>Shift</kbd> keys are changed to the left key.
+    pub fn fold_modifier_side(mut self) -> Self {
+        let code = match self.code {
+            Code::AltRight => Code::AltLeft,
+            Code::ControlRight => Code::ControlLeft,
+            Code::MetaRight => Code::MetaLeft,
+            Code::ShiftRight => Code::ShiftLeft,
+            _ => return self,
+        };
+        self.code = code;
+        self.location = Location::Left;
+        self
+    }
+}
+
+/// Format the event on a single line for logging.
+///
+/// The format is the [event type](KeyState::event_type), the modifiers as
+/// formatted by their [`Display`](fmt::Display) implementation (omitted if
+/// empty), the code and the key. Character values are quoted.
+///
+/// ```rust
+/// # use keyboard_types::{Code, Key, KeyboardEvent, Modifiers};
+/// let event = KeyboardEvent {
+///     modifiers: Modifiers::CONTROL | Modifiers::SHIFT,
+///     ..KeyboardEvent::key_down(Key::Character("S".to_owned()), Code::KeyS)
+/// };
+/// assert_eq!(event.to_string(), r#"keydown Control+Shift KeyS "S""#);
+/// ```
+impl fmt::Display for KeyboardEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.state.event_type())?;
+        if !self.modifiers.is_empty() {
+            write!(f, " {}", self.modifiers)?;
+        }
+        write!(f, " {} ", self.code)?;
+        match self.key {
+            Key::Character(ref c) => write!(f, "{:?}", c),
+            Key::Named(k) => write!(f, "{}", k),
+        }
+    }
+}
+
+/// Formats a [`KeyboardEvent`] for [`KeyboardEvent::compact_debug`].
+struct CompactDebug<'a>(&'a KeyboardEvent);
+
+impl fmt::Display for CompactDebug<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let event = self.0;
+        f.write_str(event.state.event_type())?;
+        f.write_str(" ")?;
+        f.write_str(event.code.as_str())?;
+        f.write_str(" ")?;
+        match event.key {
+            Key::Character(ref c) => {
+                f.write_str("\"")?;
+                f.write_str(c)?;
+                f.write_str("\"")?;
+            }
+            Key::Named(k) => f.write_str(k.as_str())?,
+        }
+        if event.location != Location::Standard {
+            f.write_str(" ")?;
+            f.write_str(event.location.as_str())?;
+        }
+        if !event.modifiers.is_empty() {
+            f.write_str(" ")?;
+            fmt::Display::fmt(&event.modifiers, f)?;
+        }
+        if event.repeat {
+            f.write_str(" repeat")?;
+        }
+        if event.is_composing {
+            f.write_str(" composing")?;
+        }
+        Ok(())
+    }
+}
+
+/// Builder for a [`KeyboardEvent`].
+///
+/// Created with [`KeyboardEvent::builder`].
+#[derive(Clone, Debug, Default)]
+pub struct KeyboardEventBuilder {
+    event: KeyboardEvent,
+}
+
+impl KeyboardEventBuilder {
+    /// Set whether the key is pressed or released.
+    pub fn state(mut self, state: KeyState) -> Self {
+        self.event.state = state;
+        self
+    }
+
+    /// Set the logical key value.
+    pub fn key(mut self, key: impl Into<Key>) -> Self {
+        self.event.key = key.into();
+        self
+    }
+
+    /// Set the physical key position.
+    pub fn code(mut self, code: Code) -> Self {
+        self.event.code = code;
+        self
+    }
+
+    /// Set the location of the key.
+    pub fn location(mut self, location: Location) -> Self {
+        self.event.location = location;
+        self
+    }
+
+    /// Set the pressed modifier keys.
+    pub fn modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.event.modifiers = modifiers;
+        self
+    }
+
+    /// Set whether the key is auto-repeated.
+    pub fn repeat(mut self, repeat: bool) -> Self {
+        self.event.repeat = repeat;
+        self
+    }
+
+    /// Set whether the event is part of a composition session.
+    pub fn is_composing(mut self, is_composing: bool) -> Self {
+        self.event.is_composing = is_composing;
+        self
+    }
+
+    /// Set the time of the event in milliseconds.
+    pub fn timestamp(mut self, timestamp: u64) -> Self {
+        self.event.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Create the keyboard event.
+    pub fn build(self) -> KeyboardEvent {
+        self.event
+    }
+}
+
+/// Assemble the text typed by a sequence of keyboard events.
+///
+/// Character values of key down events are appended to the text and
+/// [`NamedKey::Backspace`] removes the last character. [`NamedKey::Enter`]
+/// appends a newline (`"\n"`). All other keys, key up events and events
+/// with [`is_composing`](KeyboardEvent::is_composing) set are ignored.
+///
+/// This is meant for simple inputs and test harnesses, it does not handle
+/// composition or cursor movement.
+pub fn compose_text(events: impl IntoIterator<Item = KeyboardEvent>) -> String {
+    let mut text = String::new();
+    for event in events {
+        if !event.state.is_down() || event.is_composing {
+            continue;
+        }
+        match event.key {
+            Key::Character(c) => text.push_str(&c),
+            Key::Named(NamedKey::Backspace) => {
+                text.pop();
+            }
+            Key::Named(NamedKey::Enter) => text.push('\n'),
+            Key::Named(_) => {}
+        }
+    }
+    text
+}
+
+/// Collapse runs of auto-repeated events.
+///
+/// Consecutive events with [`repeat`](KeyboardEvent::repeat) set and the
+/// same [`code`](KeyboardEvent::code) are replaced by the first event of the
+/// run. All other events, including the initial key down event before the
+/// repeats, are kept in order.
+pub fn coalesce_repeats(events: &[KeyboardEvent]) -> Vec<KeyboardEvent> {
+    let mut coalesced: Vec<KeyboardEvent> = Vec::with_capacity(events.len());
+    for event in events {
+        if let Some(last) = coalesced.last() {
+            if event.repeat && last.repeat && event.code == last.code {
+                continue;
+            }
+        }
+        coalesced.push(event.clone());
+    }
+    coalesced
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn infer_location() {
+        let event = KeyboardEvent::key_down(NamedKey::Shift, Code::ShiftRight).infer_location();
+        assert_eq!(event.location, Location::Right);
+
+        let event = KeyboardEvent {
+            location: Location::Left,
+            ..KeyboardEvent::key_down(NamedKey::Shift, Code::ShiftRight)
+        };
+        assert_eq!(event.infer_location().location, Location::Left);
+    }
+
+    #[test]
+    fn flip_state() {
+        let down = KeyboardEvent {
+            repeat: true,
+            ..KeyboardEvent::key_down(Key::from('a'), Code::KeyA)
+        };
+        let up = down.flip_state();
+        assert_eq!(up.state, KeyState::Up);
+        assert!(!up.repeat);
+        assert_eq!(up.key, down.key);
+        assert_eq!(up.code, down.code);
+        assert_eq!(up.flip_state().state, KeyState::Down);
+    }
+
+    #[test]
+    fn redacted() {
+        use alloc::string::ToString;
+
+        let event = KeyboardEvent {
+            modifiers: Modifiers::SHIFT,
+            ..KeyboardEvent::key_down(Key::Character("P".to_string()), Code::KeyP)
+        };
+        let redacted = event.redacted();
+        assert_eq!(redacted.key, Key::Character("•".to_string()));
+        assert_eq!(redacted.code, Code::KeyP);
+        assert_eq!(redacted.state, KeyState::Down);
+        assert_eq!(redacted.modifiers, Modifiers::SHIFT);
+
+        let event = KeyboardEvent::key_up(NamedKey::Enter, Code::Enter);
+        assert_eq!(event.redacted(), event);
+    }
+
+    #[test]
+    fn compact_debug() {
+        use alloc::string::ToString;
+
+        let event = KeyboardEvent {
+            modifiers: Modifiers::SHIFT | Modifiers::CAPS_LOCK,
+            is_composing: true,
+            timestamp: Some(42),
+            ..KeyboardEvent::key_up(Key::Character("S".to_string()), Code::KeyS)
+        };
+        assert_eq!(
+            event.compact_debug().to_string(),
+            r#"keyup KeyS "S" Shift+CapsLock composing"#
+        );
+        let event = KeyboardEvent::key_down(NamedKey::Enter, Code::NumpadEnter).infer_location();
+        assert_eq!(
+            event.compact_debug().to_string(),
+            "keydown NumpadEnter Enter Numpad"
+        );
+    }
+
+    #[test]
+    fn is_shortcut_candidate() {
+        use alloc::string::ToString;
+
+        let event = KeyboardEvent::key_down(Key::Character("s".to_string()), Code::KeyS);
+        assert!(event.is_shortcut_candidate());
+        let modifier = KeyboardEvent {
+            modifiers: Modifiers::CONTROL,
+            ..KeyboardEvent::key_down(NamedKey::Control, Code::ControlLeft)
+        };
+        assert!(!modifier.is_shortcut_candidate());
+        let repeat = KeyboardEvent {
+            repeat: true,
+            ..event.clone()
+        };
+        assert!(!repeat.is_shortcut_candidate());
+        let up = KeyboardEvent::key_up(Key::Character("s".to_string()), Code::KeyS);
+        assert!(!up.is_shortcut_candidate());
+    }
+
+    #[test]
+    fn is_modifier_event() {
+        use alloc::string::ToString;
+
+        let shift = KeyboardEvent::key_down(NamedKey::Shift, Code::ShiftLeft);
+        assert!(shift.is_modifier_event());
+        let up = KeyboardEvent::key_up(NamedKey::Shift, Code::ShiftLeft);
+        assert!(up.is_modifier_event());
+        let letter = KeyboardEvent::key_down(Key::Character("a".to_string()), Code::KeyA);
+        assert!(!letter.is_modifier_event());
+    }
+
+    #[test]
+    fn captured_binding() {
+        use alloc::string::ToString;
+
+        let event = KeyboardEvent {
+            modifiers: Modifiers::CONTROL,
+            ..KeyboardEvent::key_down(Key::Character("k".to_string()), Code::KeyK)
+        };
+        assert_eq!(
+            event.captured_binding(),
+            Some((Modifiers::CONTROL, Key::Character("k".to_string())))
+        );
+        let shift = KeyboardEvent {
+            modifiers: Modifiers::SHIFT,
+            ..KeyboardEvent::key_down(NamedKey::Shift, Code::ShiftLeft)
+        };
+        assert_eq!(shift.captured_binding(), None);
+        let up = KeyboardEvent::key_up(Key::Character("k".to_string()), Code::KeyK);
+        assert_eq!(up.captured_binding(), None);
+    }
+
+    #[test]
+    fn canonicalize() {
+        use alloc::string::ToString;
+
+        // AltGr + Q on a German keyboard.
+        let windows = KeyboardEvent {
+            modifiers: Modifiers::CONTROL | Modifiers::ALT | Modifiers::ALT_GRAPH,
+            ..KeyboardEvent::key_down(Key::Character("@".to_string()), Code::KeyQ)
+        };
+        let macos = KeyboardEvent {
+            modifiers: Modifiers::ALT_GRAPH,
+            ..KeyboardEvent::key_down(Key::Character("@".to_string()), Code::KeyQ)
+        };
+        assert_ne!(windows, macos);
+        assert_eq!(windows.canonicalize(), macos.canonicalize());
+
+        // Shift + Enter on the numpad.
+        let numpad = KeyboardEvent {
+            location: Location::Numpad,
+            modifiers: Modifiers::SHIFT,
+            ..KeyboardEvent::key_down(NamedKey::Enter, Code::NumpadEnter)
+        };
+        let event = numpad.canonicalize();
+        assert_eq!(event.code, Code::Enter);
+        assert_eq!(event.location, Location::Standard);
+
+        // Numpad 1 with NumLock on and off.
+        let num_lock = KeyboardEvent {
+            location: Location::Numpad,
+            modifiers: Modifiers::NUM_LOCK,
+            ..KeyboardEvent::key_down(Key::Character("1".to_string()), Code::Numpad1)
+        };
+        let event = num_lock.canonicalize();
+        assert_eq!(event.code, Code::Digit1);
+        assert_eq!(event.location, Location::Standard);
+        let end = KeyboardEvent {
+            location: Location::Numpad,
+            ..KeyboardEvent::key_down(NamedKey::End, Code::Numpad1)
+        };
+        assert_eq!(end.canonicalize(), end);
+        let delete = KeyboardEvent {
+            location: Location::Numpad,
+            ..KeyboardEvent::key_down(NamedKey::Delete, Code::NumpadDecimal)
+        };
+        assert_eq!(delete.canonicalize(), delete);
+
+        let shift = KeyboardEvent::key_down(NamedKey::Shift, Code::ShiftRight).infer_location();
+        let event = shift.canonicalize();
+        assert_eq!(event.code, Code::ShiftLeft);
+        assert_eq!(event.location, Location::Left);
+    }
+
+    #[test]
+    fn compose_text() {
+        use alloc::string::ToString;
+        use alloc::vec;
+
+        let events = vec![
+            KeyboardEvent::key_down(Key::Character("a".to_string()), Code::KeyA),
+            KeyboardEvent::key_up(Key::Character("a".to_string()), Code::KeyA),
+            KeyboardEvent::key_down(Key::Character("b".to_string()), Code::KeyB),
+            KeyboardEvent::key_down(NamedKey::Backspace, Code::Backspace),
+            KeyboardEvent::key_down(Key::Character("c".to_string()), Code::KeyC),
+        ];
+        assert_eq!(super::compose_text(events), "ac");
+    }
+
+    #[test]
+    fn coalesce_repeats() {
+        let down = KeyboardEvent::key_down(NamedKey::ArrowDown, Code::ArrowDown);
+        let repeat = KeyboardEvent {
+            repeat: true,
+            ..down.clone()
+        };
+        let burst = [repeat.clone(), repeat.clone(), repeat.clone()];
+        assert_eq!(super::coalesce_repeats(&burst).len(), 1);
+
+        let other = KeyboardEvent {
+            repeat: true,
+            ..KeyboardEvent::key_down(NamedKey::ArrowUp, Code::ArrowUp)
+        };
+        let events = [
+            down.clone(),
+            repeat.clone(),
+            repeat.clone(),
+            other.clone(),
+            repeat.clone(),
+        ];
+        assert_eq!(
+            super::coalesce_repeats(&events),
+            [down, repeat.clone(), other, repeat]
+        );
+    }
+
+    #[test]
+    fn display() {
+        use alloc::string::ToString;
+
+        let event = KeyboardEvent {
+            modifiers: Modifiers::CONTROL | Modifiers::SHIFT,
+            ..KeyboardEvent::key_down(Key::Character("S".to_string()), Code::KeyS)
+        };
+        assert_eq!(event.to_string(), r#"keydown Control+Shift KeyS "S""#);
+        let event = KeyboardEvent::key_up(NamedKey::Enter, Code::NumpadEnter);
+        assert_eq!(event.to_string(), "keyup NumpadEnter Enter");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_default() {
+        let event = KeyboardEvent::default();
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(serde_json::from_str::<KeyboardEvent>(&json).unwrap(), event);
+    }
+
+    #[test]
+    fn timestamp() {
+        assert_eq!(KeyboardEvent::default().timestamp, None);
+        let event = KeyboardEvent::key_down(NamedKey::Enter, Code::Enter);
+        assert_eq!(event.timestamp, None);
+
+        let earlier = KeyboardEvent {
+            timestamp: Some(1000),
+            ..event.clone()
+        };
+        let later = KeyboardEvent {
+            timestamp: Some(2000),
+            ..event.clone()
+        };
+        assert_ne!(earlier, later);
+        assert!(event < earlier && earlier < later);
+        assert_eq!(
+            KeyboardEvent {
+                timestamp: None,
+                ..later
+            },
+            event
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_minimal() {
+        let event = KeyboardEvent::key_down(NamedKey::Enter, Code::Enter);
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(
+            json,
+            r#"{"state":"Down","key":{"Named":"Enter"},"code":"Enter"}"#
+        );
+        assert_eq!(serde_json::from_str::<KeyboardEvent>(&json).unwrap(), event);
+
+        let event = KeyboardEvent {
+            modifiers: Modifiers::CONTROL,
+            repeat: true,
+            timestamp: Some(5),
+            ..event
+        };
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(serde_json::from_str::<KeyboardEvent>(&json).unwrap(), event);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_dom_names() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Message {
+            #[serde(with = "super::dom_names")]
+            event: KeyboardEvent,
+        }
+
+        let message = Message {
+            event: KeyboardEvent {
+                location: Location::Numpad,
+                modifiers: Modifiers::CONTROL | Modifiers::SHIFT,
+                repeat: true,
+                is_composing: true,
+                timestamp: Some(1234),
+                ..KeyboardEvent::key_up(NamedKey::Enter, Code::NumpadEnter)
+            },
+        };
+        let json = r#"{"event":{"state":"Up","key":{"Named":"Enter"},"code":"NumpadEnter","location":"Numpad","modifiers":"CONTROL | SHIFT","repeat":true,"isComposing":true,"timestamp":1234}}"#;
+        assert_eq!(serde_json::to_string(&message).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Message>(json).unwrap(), message);
+
+        let message = Message {
+            event: KeyboardEvent::key_down(NamedKey::Process, Code::KeyA),
+        };
+        let json = r#"{"event":{"state":"Down","key":{"Named":"Process"},"code":"KeyA"}}"#;
+        assert_eq!(serde_json::to_string(&message).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Message>(json).unwrap(), message);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_without_timestamp() {
+        let json = r#"{"state":"Down","key":{"Named":"Enter"},"code":"Enter","location":"Standard","modifiers":"","repeat":false,"is_composing":false}"#;
+        let event: KeyboardEvent = serde_json::from_str(json).unwrap();
+        assert_eq!(event, KeyboardEvent::key_down(NamedKey::Enter, Code::Enter));
+    }
+
+    // The serialized form is part of the stable API and must not change in
+    // patch releases.
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_golden() {
+        use alloc::string::ToString;
+
+        let events = [
+            KeyboardEvent {
+                location: Location::Numpad,
+                modifiers: Modifiers::CONTROL | Modifiers::SHIFT,
+                repeat: true,
+                is_composing: true,
+                timestamp: Some(1234),
+                ..KeyboardEvent::key_up(NamedKey::Enter, Code::NumpadEnter)
+            },
+            KeyboardEvent::key_down(Key::Character("S".to_string()), Code::KeyS),
+        ];
+        let golden = [
+            r#"{"state":"Up","key":{"Named":"Enter"},"code":"NumpadEnter","location":"Numpad","modifiers":"CONTROL | SHIFT","repeat":true,"is_composing":true,"timestamp":1234}"#,
+            r#"{"state":"Down","key":{"Character":"S"},"code":"KeyS"}"#,
+        ];
+        for (event, json) in events.iter().zip(golden) {
+            assert_eq!(serde_json::to_string(event).unwrap(), json);
+            assert_eq!(&serde_json::from_str::<KeyboardEvent>(json).unwrap(), event);
+        }
+    }
 }
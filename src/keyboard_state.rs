@@ -0,0 +1,112 @@
+use alloc::vec::Vec;
+
+use crate::{Code, KeyState, KeyboardEvent, Modifiers};
+
+/// Track the pressed keys and modifiers from a stream of keyboard events.
+///
+/// ```rust
+/// # use keyboard_types::{Code, KeyboardEvent, KeyboardState, Modifiers, NamedKey};
+/// let mut state = KeyboardState::new();
+/// state.process(&KeyboardEvent {
+///     modifiers: Modifiers::SHIFT,
+///     ..KeyboardEvent::key_down(NamedKey::Shift, Code::ShiftLeft)
+/// });
+/// assert!(state.is_pressed(Code::ShiftLeft));
+/// assert_eq!(state.modifiers(), Modifiers::SHIFT);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct KeyboardState {
+    pressed: Vec<Code>,
+    modifiers: Modifiers,
+    previous_modifiers: Modifiers,
+}
+
+impl KeyboardState {
+    /// Create a state with no keys pressed.
+    pub fn new() -> KeyboardState {
+        KeyboardState {
+            pressed: Vec::new(),
+            modifiers: Modifiers::empty(),
+            previous_modifiers: Modifiers::empty(),
+        }
+    }
+
+    /// Update the state with the next event.
+    ///
+    /// The modifiers are taken from the [`modifiers`](KeyboardEvent::modifiers)
+    /// of the event. Events with [`Code::Unidentified`] do not change the
+    /// pressed keys.
+    pub fn process(&mut self, event: &KeyboardEvent) {
+        if event.code != Code::Unidentified {
+            let index = self.pressed.iter().position(|&code| code == event.code);
+            match (event.state, index) {
+                (KeyState::Down, None) => self.pressed.push(event.code),
+                (KeyState::Up, Some(index)) => {
+                    self.pressed.swap_remove(index);
+                }
+                _ => {}
+            }
+        }
+        self.previous_modifiers = self.modifiers;
+        self.modifiers = event.modifiers;
+    }
+
+    /// Return `true` if the key is pressed.
+    pub fn is_pressed(&self, code: Code) -> bool {
+        self.pressed.contains(&code)
+    }
+
+    /// The pressed keys, in no particular order.
+    pub fn pressed(&self) -> &[Code] {
+        &self.pressed
+    }
+
+    /// The modifiers of the most recent event.
+    pub fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+
+    /// The modifiers that were pressed and released by the most recent event.
+    ///
+    /// Returns `None` if the modifiers did not change.
+    pub fn modifiers_changed(&self) -> Option<(Modifiers, Modifiers)> {
+        let changed = self.modifiers ^ self.previous_modifiers;
+        if changed.is_empty() {
+            None
+        } else {
+            Some((changed & self.modifiers, changed & self.previous_modifiers))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::NamedKey;
+
+    #[test]
+    fn modifiers_changed() {
+        let mut state = KeyboardState::new();
+        assert_eq!(state.modifiers_changed(), None);
+        state.process(&KeyboardEvent {
+            modifiers: Modifiers::SHIFT,
+            ..KeyboardEvent::key_down(NamedKey::Shift, Code::ShiftLeft)
+        });
+        assert_eq!(
+            state.modifiers_changed(),
+            Some((Modifiers::SHIFT, Modifiers::empty()))
+        );
+        state.process(&KeyboardEvent {
+            modifiers: Modifiers::SHIFT,
+            ..KeyboardEvent::key_down(NamedKey::Enter, Code::Enter)
+        });
+        assert_eq!(state.modifiers_changed(), None);
+        state.process(&KeyboardEvent::key_up(NamedKey::Shift, Code::ShiftLeft));
+        assert_eq!(
+            state.modifiers_changed(),
+            Some((Modifiers::empty(), Modifiers::SHIFT))
+        );
+        assert!(!state.is_pressed(Code::ShiftLeft));
+        assert!(state.is_pressed(Code::Enter));
+    }
+}
@@ -0,0 +1,109 @@
+//! Human readable labels for physical and named keys.
+
+use crate::{Code, NamedKey};
+
+/// Provides human readable labels for keys, e.g. for a settings dialog.
+///
+/// Implement this trait to translate key names into the user's language.
+/// The provided methods return English labels, so only the keys that
+/// differ need to be handled. [`EnglishLabels`] uses the defaults.
+///
+/// ```rust
+/// # use keyboard_types::{Code, KeyLabels, NamedKey};
+/// struct German;
+///
+/// impl KeyLabels for German {
+///     fn named_key_label(&self, key: NamedKey) -> &str {
+///         match key {
+///             NamedKey::Enter => "Eingabe",
+///             _ => self.default_named_key_label(key),
+///         }
+///     }
+/// }
+///
+/// assert_eq!(German.named_key_label(NamedKey::Enter), "Eingabe");
+/// assert_eq!(German.code_label(Code::KeyA), "A");
+/// ```
+pub trait KeyLabels {
+    /// The label of a physical key.
+    fn code_label(&self, code: Code) -> &str {
+        self.default_code_label(code)
+    }
+
+    /// The label of a named key.
+    fn named_key_label(&self, key: NamedKey) -> &str {
+        self.default_named_key_label(key)
+    }
+
+    /// The English label of a physical key.
+    ///
+    /// Letter and digit keys are labeled with the character they produce on
+    /// a US keyboard, e.g. `A` for [`Code::KeyA`]. Other keys use the name
+    /// of the code.
+    fn default_code_label(&self, code: Code) -> &str {
+        let name = code.as_str();
+        match name.as_bytes() {
+            [b'K', b'e', b'y', _] => &name[3..],
+            [b'D', b'i', b'g', b'i', b't', _] => &name[5..],
+            _ => name,
+        }
+    }
+
+    /// The English label of a named key, which is the name of the key.
+    fn default_named_key_label(&self, key: NamedKey) -> &str {
+        key.as_str()
+    }
+}
+
+/// The English labels provided by [`KeyLabels`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct EnglishLabels;
+
+impl KeyLabels for EnglishLabels {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Labels;
+
+    impl KeyLabels for Labels {
+        fn code_label(&self, code: Code) -> &str {
+            match code {
+                Code::Space => "Leertaste",
+                _ => self.default_code_label(code),
+            }
+        }
+
+        fn named_key_label(&self, key: NamedKey) -> &str {
+            match key {
+                NamedKey::Delete => "Entf",
+                _ => self.default_named_key_label(key),
+            }
+        }
+    }
+
+    #[test]
+    fn english() {
+        assert_eq!(EnglishLabels.code_label(Code::KeyQ), "Q");
+        assert_eq!(EnglishLabels.code_label(Code::Digit7), "7");
+        assert_eq!(EnglishLabels.code_label(Code::Space), "Space");
+        assert_eq!(
+            EnglishLabels.code_label(Code::KeyboardBacklightToggle),
+            "KeyboardBacklightToggle"
+        );
+        assert_eq!(
+            EnglishLabels.code_label(Code::KeyboardLayoutSelect),
+            "KeyboardLayoutSelect"
+        );
+        assert_eq!(EnglishLabels.named_key_label(NamedKey::Escape), "Escape");
+    }
+
+    #[test]
+    fn custom() {
+        assert_eq!(Labels.code_label(Code::Space), "Leertaste");
+        assert_eq!(Labels.code_label(Code::KeyQ), "Q");
+        assert_eq!(Labels.named_key_label(NamedKey::Delete), "Entf");
+        assert_eq!(Labels.named_key_label(NamedKey::Enter), "Enter");
+    }
+}
@@ -14,25 +14,77 @@ extern crate std;
 
 pub use crate::code::{Code, UnrecognizedCodeError};
 pub use crate::composition::{CompositionEvent, CompositionState};
+pub use crate::dead_keys::DeadKeyComposer;
+pub use crate::finger::Finger;
+#[cfg(feature = "heapless")]
+pub use crate::heapless_key::HeaplessKey;
+#[cfg(feature = "hid")]
+pub use crate::hid::HidReportKeys;
 pub use crate::key::{Key, UnrecognizedKeyError};
 pub use crate::key_state::KeyState;
-pub use crate::keyboard_event::KeyboardEvent;
-pub use crate::location::Location;
-pub use crate::modifiers::Modifiers;
+pub use crate::keyboard_event::{
+    coalesce_repeats, compose_text, KeyboardEvent, KeyboardEventBuilder,
+};
+pub use crate::keyboard_state::KeyboardState;
+pub use crate::labels::{EnglishLabels, KeyLabels};
+#[cfg(feature = "linux")]
+pub use crate::linux::parse_linux_keymap_line;
+pub use crate::location::{Location, UnrecognizedLocationError};
+pub use crate::media::MediaAction;
+pub use crate::modifiers::{InvalidModifierBits, Modifiers, Platform, UnrecognizedModifierError};
 pub use crate::named_key::{NamedKey, UnrecognizedNamedKeyError};
-pub use crate::shortcuts::ShortcutMatcher;
+pub use crate::numbering::InvalidKeyNumber;
+pub use crate::repeat_filter::RepeatFilter;
+pub use crate::shortcuts::{
+    parse_shortcut, ChordSet, ModifierPattern, ParseShortcutError, Shortcut, ShortcutMatcher,
+    ShortcutTable,
+};
+pub use crate::us_layout::layout_consistent;
+pub use crate::utf16::Utf16KeyDecoder;
+pub use crate::validator::{KeyEventError, KeyEventValidator};
 
 mod code;
-mod composition;
+mod code_helpers;
+pub mod composition;
+mod dead_keys;
+#[cfg(feature = "evdev")]
+mod evdev;
+mod finger;
+#[cfg(feature = "gtk")]
+mod gtk;
+#[cfg(feature = "heapless")]
+mod heapless_key;
+#[cfg(feature = "hid")]
+mod hid;
 mod key;
 mod key_state;
-mod keyboard_event;
+pub mod keyboard_event;
+mod keyboard_state;
+mod labels;
+#[cfg(feature = "linux")]
+mod linux;
 mod location;
-mod modifiers;
+mod media;
+pub mod modifiers;
 mod named_key;
+mod numbering;
+#[cfg(feature = "ps2")]
+mod ps2;
+mod repeat_filter;
+#[cfg(feature = "sdl2")]
+mod sdl2;
 mod shortcuts;
+mod us_layout;
+mod utf16;
+mod validator;
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+mod wasm;
 #[cfg(feature = "webdriver")]
 pub mod webdriver;
+#[cfg(feature = "winit")]
+pub mod winit;
+#[cfg(feature = "xkb")]
+mod xkb;
 
 impl Default for NamedKey {
     fn default() -> Self {
@@ -46,6 +98,63 @@ impl Default for Code {
     }
 }
 
+impl AsRef<str> for NamedKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Code {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl TryFrom<&str> for NamedKey {
+    type Error = UnrecognizedNamedKeyError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl TryFrom<&str> for Code {
+    type Error = UnrecognizedCodeError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Create a [`Code`] from a string literal, validated at compile time.
+///
+/// Unlike [`Code::from_str`](core::str::FromStr::from_str) a misspelled code is
+/// caught when compiling instead of at runtime.
+///
+/// ```rust
+/// use keyboard_types::{code, Code};
+///
+/// const SAVE: Code = code!("KeyS");
+/// assert_eq!(SAVE, Code::KeyS);
+/// assert_eq!(code!("OSLeft"), Code::MetaLeft);
+/// ```
+///
+/// ```compile_fail
+/// let code = keyboard_types::code!("KyeA");
+/// ```
+#[macro_export]
+macro_rules! code {
+    ($code:literal) => {{
+        const CODE: $crate::Code = match $crate::Code::from_str_const($code) {
+            ::core::option::Option::Some(code) => code,
+            ::core::option::Option::None => {
+                ::core::panic!(::core::concat!("unrecognized code: ", $code))
+            }
+        };
+        CODE
+    }};
+}
+
 /// Return the first codepoint of a string.
 ///
 /// # Panics
@@ -0,0 +1,99 @@
+//! Conversions for the Linux console keymaps used by `loadkeys` and `dumpkeys`.
+//!
+//! Keymaps identify keys by the Linux evdev key codes.
+//!
+//! Specification: <https://man7.org/linux/man-pages/man5/keymaps.5.html>
+
+use alloc::vec::Vec;
+
+use crate::Code;
+
+impl Code {
+    /// Get the code for a key code of a Linux console keymap.
+    ///
+    /// Console keymaps use the evdev key codes, so this is the same as
+    /// [`Code::from_evdev_keycode`].
+    pub fn from_linux_keymap_keycode(keycode: u16) -> Option<Code> {
+        Code::from_evdev_keycode(keycode)
+    }
+
+    /// Get the key code of a Linux console keymap for a code.
+    ///
+    /// Console keymaps use the evdev key codes, so this is the same as
+    /// [`Code::to_evdev_keycode`].
+    pub fn to_linux_keymap_keycode(self) -> Option<u16> {
+        self.to_evdev_keycode()
+    }
+}
+
+/// Parse a key code definition of a Linux console keymap, like `keycode 30 = a A`.
+///
+/// Returns the code and the action names of the definition, one for each
+/// modifier column. A leading modifier list like in `shift keycode 30 = A`
+/// is skipped, and so is a comment starting with `#` or `!`. Returns `None`
+/// if the line is not a key code definition, e.g. a comment, or if the key
+/// code has no corresponding [`Code`].
+///
+/// ```rust
+/// # use keyboard_types::{parse_linux_keymap_line, Code};
+/// assert_eq!(
+///     parse_linux_keymap_line("keycode 30 = +a A"),
+///     Some((Code::KeyA, vec!["+a", "A"]))
+/// );
+/// ```
+pub fn parse_linux_keymap_line(line: &str) -> Option<(Code, Vec<&str>)> {
+    let line = line
+        .find(['#', '!'])
+        .map_or(line, |comment| &line[..comment]);
+    let (definition, actions) = line.split_once('=')?;
+    let mut words = definition.split_whitespace();
+    words.find(|&word| word == "keycode")?;
+    let keycode = words.next()?.parse().ok()?;
+    if words.next().is_some() {
+        return None;
+    }
+    let code = Code::from_linux_keymap_keycode(keycode)?;
+    Some((code, actions.split_whitespace().collect()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn keymap_keycode() {
+        assert_eq!(Code::KeyA.to_linux_keymap_keycode(), Some(30));
+        assert_eq!(Code::from_linux_keymap_keycode(30), Some(Code::KeyA));
+        assert_eq!(Code::from_linux_keymap_keycode(0), None);
+    }
+
+    #[test]
+    fn parse_line() {
+        assert_eq!(
+            parse_linux_keymap_line("keycode  30 = +a"),
+            Some((Code::KeyA, vec!["+a"]))
+        );
+        assert_eq!(
+            parse_linux_keymap_line("\tshift keycode 30 = +A"),
+            Some((Code::KeyA, vec!["+A"]))
+        );
+        assert_eq!(
+            parse_linux_keymap_line("keycode 1 = Escape"),
+            Some((Code::Escape, vec!["Escape"]))
+        );
+        assert_eq!(
+            parse_linux_keymap_line("keycode 30 = a A # comment"),
+            Some((Code::KeyA, vec!["a", "A"]))
+        );
+        assert_eq!(
+            parse_linux_keymap_line("keycode 30 = a A! comment"),
+            Some((Code::KeyA, vec!["a", "A"]))
+        );
+        assert_eq!(parse_linux_keymap_line("keycode 30 # = a"), None);
+        assert_eq!(parse_linux_keymap_line("# keycode 30 = a"), None);
+        assert_eq!(parse_linux_keymap_line("! keycode 30 = a"), None);
+        assert_eq!(parse_linux_keymap_line("keymaps 0-2"), None);
+        assert_eq!(parse_linux_keymap_line("keycode 0 = nul"), None);
+    }
+}
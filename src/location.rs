@@ -1,3 +1,13 @@
+use alloc::boxed::Box;
+#[cfg(not(feature = "std"))]
+use core::error::Error;
+use core::fmt;
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::{Code, NamedKey};
+
 /// The location attribute contains an indication of the physical location of the key on the device.
 ///
 /// Certain keys on the keyboard can have the same value, but are in different locations. For
@@ -7,6 +17,7 @@
 /// See also [MDN's documentation](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/location).
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum Location {
     /// The key is in its "normal" location on the keyboard.
     ///
@@ -97,3 +108,118 @@ impl Default for Location {
         Location::Standard
     }
 }
+
+impl Location {
+    /// The name of the location, as used by the [`Display`](fmt::Display) implementation.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Location::Standard => "Standard",
+            Location::Left => "Left",
+            Location::Right => "Right",
+            Location::Numpad => "Numpad",
+        }
+    }
+
+    /// The location of a key with the given physical position.
+    ///
+    /// The left and right modifier keys and the keys on the numeric keypad
+    /// have a specific location, all other keys use [`Location::Standard`].
+    pub fn from_code(code: Code) -> Location {
+        match code {
+            Code::AltLeft | Code::ControlLeft | Code::MetaLeft | Code::ShiftLeft => Location::Left,
+            Code::AltRight | Code::ControlRight | Code::MetaRight | Code::ShiftRight => {
+                Location::Right
+            }
+            code if code.is_numpad() => Location::Numpad,
+            _ => Location::Standard,
+        }
+    }
+
+    /// The location of a key with the given named key value.
+    ///
+    /// Always returns [`Location::Standard`]: a key value like
+    /// [`NamedKey::Control`] does not tell which side the key is on and a
+    /// [`NamedKey`] can be produced by the main keyboard as well as the
+    /// numeric keypad. Prefer [`Location::from_code`] if the code is known.
+    pub fn from_named_key(_key: NamedKey) -> Location {
+        Location::Standard
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Location {
+    type Err = UnrecognizedLocationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Standard" => Ok(Location::Standard),
+            "Left" => Ok(Location::Left),
+            "Right" => Ok(Location::Right),
+            "Numpad" => Ok(Location::Numpad),
+            _ => Err(UnrecognizedLocationError { input: s.into() }),
+        }
+    }
+}
+
+/// Parse from string error, returned when string does not match to any [`Location`] variant.
+#[derive(Clone, Debug)]
+pub struct UnrecognizedLocationError {
+    input: Box<str>,
+}
+
+impl UnrecognizedLocationError {
+    /// The string that failed to parse.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl fmt::Display for UnrecognizedLocationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Unrecognized location: {:?}", self.input)
+    }
+}
+
+impl Error for UnrecognizedLocationError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        use alloc::string::ToString;
+
+        for location in [
+            Location::Standard,
+            Location::Left,
+            Location::Right,
+            Location::Numpad,
+        ] {
+            assert_eq!(
+                location.to_string().parse::<Location>().ok(),
+                Some(location)
+            );
+        }
+        let err = "left".parse::<Location>().unwrap_err();
+        assert_eq!(err.input(), "left");
+    }
+
+    #[test]
+    fn from_named_key() {
+        assert_eq!(
+            Location::from_named_key(NamedKey::Control),
+            Location::Standard
+        );
+        assert_eq!(
+            Location::from_named_key(NamedKey::Enter),
+            Location::Standard
+        );
+        assert_eq!(Location::from_code(Code::ControlRight), Location::Right);
+    }
+}
@@ -0,0 +1,77 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::NamedKey;
+
+/// A normalized media transport action.
+///
+/// Several [`NamedKey`] values describe the same action of a media player,
+/// use [`NamedKey::media_action`] to map them.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MediaAction {
+    /// Start or resume playback.
+    Play,
+    /// Pause playback.
+    Pause,
+    /// Toggle between playing and paused.
+    PlayPause,
+    /// Stop playback.
+    Stop,
+    /// Skip to the next track.
+    Next,
+    /// Skip to the previous track.
+    Previous,
+    /// Increase the volume.
+    VolumeUp,
+    /// Decrease the volume.
+    VolumeDown,
+    /// Toggle muting the audio.
+    Mute,
+}
+
+impl NamedKey {
+    /// The media transport action of a key, if any.
+    ///
+    /// Only the keys intended for media controllers are mapped, for example
+    /// [`NamedKey::MediaPause`] but not [`NamedKey::Pause`].
+    pub fn media_action(self) -> Option<MediaAction> {
+        match self {
+            NamedKey::MediaPlay => Some(MediaAction::Play),
+            NamedKey::MediaPause => Some(MediaAction::Pause),
+            NamedKey::MediaPlayPause => Some(MediaAction::PlayPause),
+            NamedKey::MediaStop => Some(MediaAction::Stop),
+            NamedKey::MediaTrackNext => Some(MediaAction::Next),
+            NamedKey::MediaTrackPrevious => Some(MediaAction::Previous),
+            NamedKey::AudioVolumeUp => Some(MediaAction::VolumeUp),
+            NamedKey::AudioVolumeDown => Some(MediaAction::VolumeDown),
+            NamedKey::AudioVolumeMute => Some(MediaAction::Mute),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn media_action() {
+        let cases = [
+            (NamedKey::MediaPlay, MediaAction::Play),
+            (NamedKey::MediaPause, MediaAction::Pause),
+            (NamedKey::MediaPlayPause, MediaAction::PlayPause),
+            (NamedKey::MediaStop, MediaAction::Stop),
+            (NamedKey::MediaTrackNext, MediaAction::Next),
+            (NamedKey::MediaTrackPrevious, MediaAction::Previous),
+            (NamedKey::AudioVolumeUp, MediaAction::VolumeUp),
+            (NamedKey::AudioVolumeDown, MediaAction::VolumeDown),
+            (NamedKey::AudioVolumeMute, MediaAction::Mute),
+        ];
+        for (key, action) in cases {
+            assert_eq!(key.media_action(), Some(action));
+        }
+        assert_eq!(NamedKey::Pause.media_action(), None);
+        assert_eq!(NamedKey::Enter.media_action(), None);
+    }
+}
@@ -5,6 +5,16 @@
 //!
 //! Use the constants to match for combinations of the modifier keys.
 
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+#[cfg(not(feature = "std"))]
+use core::error::Error;
+use core::fmt;
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::error::Error;
+
 bitflags::bitflags! {
     /// Pressed modifier keys.
     ///
@@ -33,6 +43,146 @@ bitflags::bitflags! {
 }
 
 impl Modifiers {
+    /// Convert from underlying bit representation, failing on unknown bits.
+    ///
+    /// Unlike [`Modifiers::from_bits`] the error reports which bits
+    /// do not correspond to any modifier.
+    pub fn from_bits_checked(bits: u32) -> Result<Modifiers, InvalidModifierBits> {
+        let invalid = bits & !Modifiers::all().bits();
+        if invalid != 0 {
+            return Err(InvalidModifierBits(invalid));
+        }
+        Ok(Modifiers::from_bits_retain(bits))
+    }
+
+    /// Combine a slice of modifiers.
+    ///
+    /// This is useful if the modifiers are only known at runtime. For a fixed
+    /// set of modifiers use the `|` operator instead, e.g.
+    /// `Modifiers::CONTROL | Modifiers::SHIFT`.
+    ///
+    /// ```rust
+    /// # use keyboard_types::Modifiers;
+    /// assert_eq!(
+    ///     Modifiers::all_of(&[Modifiers::CONTROL, Modifiers::SHIFT]),
+    ///     Modifiers::CONTROL | Modifiers::SHIFT
+    /// );
+    /// ```
+    pub const fn all_of(modifiers: &[Modifiers]) -> Modifiers {
+        let mut bits = 0;
+        let mut i = 0;
+        while i < modifiers.len() {
+            bits |= modifiers[i].bits();
+            i += 1;
+        }
+        Modifiers::from_bits_retain(bits)
+    }
+
+    /// Keep only the modifiers that form a key chord.
+    ///
+    /// The shift, control, alt, meta and `AltGraph` modifiers are kept.
+    /// Lock states like [`Modifiers::CAPS_LOCK`] and the other modifiers
+    /// are removed, so the result can be compared when matching shortcuts.
+    ///
+    /// The shortcut matchers of this crate use
+    /// [`shortcut_only`](Self::shortcut_only) instead, which also removes
+    /// `AltGraph`.
+    pub fn chord_only(self) -> Modifiers {
+        self & (Modifiers::SHIFT
+            | Modifiers::CONTROL
+            | Modifiers::ALT
+            | Modifiers::META
+            | Modifiers::ALT_GRAPH)
+    }
+
+    /// Keep only the shift, control, alt and meta modifiers.
+    ///
+    /// These are the modifiers compared by [`ShortcutMatcher`](crate::ShortcutMatcher),
+    /// [`ShortcutTable`](crate::ShortcutTable) and [`Shortcut`](crate::Shortcut).
+    /// Unlike [`chord_only`](Self::chord_only) the `AltGraph` modifier is
+    /// removed as well: it selects which character a key produces, so it is
+    /// already reflected in the key value of the event.
+    pub const fn shortcut_only(self) -> Modifiers {
+        Modifiers::from_bits_retain(
+            self.bits()
+                & (Modifiers::SHIFT.bits()
+                    | Modifiers::CONTROL.bits()
+                    | Modifiers::ALT.bits()
+                    | Modifiers::META.bits()),
+        )
+    }
+
+    /// The number of modifiers that form a key chord.
+    ///
+    /// Only the modifiers kept by [`Modifiers::chord_only`] are counted,
+    /// so lock states like [`Modifiers::CAPS_LOCK`] are ignored.
+    pub fn count(self) -> u32 {
+        self.chord_only().bits().count_ones()
+    }
+
+    /// Return `true` if all modifiers in `other` are also pressed in `self`.
+    ///
+    /// For shortcuts this means the held modifiers satisfy the modifiers a
+    /// binding requires, possibly with more modifiers held. Same as
+    /// [`contains`](Self::contains).
+    pub const fn is_superset_of(self, other: Modifiers) -> bool {
+        self.contains(other)
+    }
+
+    /// Return `true` if no modifier is pressed in both `self` and `other`.
+    ///
+    /// For shortcuts this means none of the modifiers a binding forbids
+    /// are held, see also [`ModifierPattern`](crate::ModifierPattern).
+    pub const fn is_disjoint(self, other: Modifiers) -> bool {
+        !self.intersects(other)
+    }
+
+    /// Format the shift, control, alt and meta modifiers for display in a user interface.
+    ///
+    /// Formatting does not allocate, so it can write into a fixed size buffer.
+    ///
+    /// On [`Platform::MacOs`] the symbols `⌃⌥⇧⌘` are used, in this order.
+    /// Otherwise the modifiers are written as words in the order
+    /// `Ctrl+Alt+Shift+Meta`. Other modifiers are not displayed.
+    ///
+    /// ```rust
+    /// # use keyboard_types::{Modifiers, Platform};
+    /// let modifiers = Modifiers::SHIFT | Modifiers::META;
+    /// assert_eq!(modifiers.display_symbols(Platform::MacOs).to_string(), "⇧⌘");
+    /// assert_eq!(modifiers.display_symbols(Platform::Other).to_string(), "Shift+Meta");
+    /// ```
+    pub fn display_symbols(self, platform: Platform) -> impl fmt::Display {
+        DisplaySymbols {
+            modifiers: self,
+            platform,
+        }
+    }
+
+    /// Describe why `actual` modifiers do not match the `expected` ones.
+    ///
+    /// The description is meant for diagnostics in a user interface, like
+    /// `expected Control, but Alt was held`. Modifiers are named as in the
+    /// specification and combined with `+`. Returns an empty string if the
+    /// modifiers are equal.
+    ///
+    /// ```rust
+    /// # use keyboard_types::Modifiers;
+    /// assert_eq!(
+    ///     Modifiers::describe_mismatch(Modifiers::CONTROL, Modifiers::ALT),
+    ///     "expected Control, but Alt was held"
+    /// );
+    /// ```
+    pub fn describe_mismatch(expected: Modifiers, actual: Modifiers) -> String {
+        let missing = expected - actual;
+        let extra = actual - expected;
+        match (missing.is_empty(), extra.is_empty()) {
+            (true, true) => String::new(),
+            (false, true) => format!("expected {}, but it was not held", Names(missing)),
+            (true, false) => format!("expected no {}, but it was held", Names(extra)),
+            (false, false) => format!("expected {}, but {} was held", Names(missing), Names(extra)),
+        }
+    }
+
     /// Return `true` if a shift key is pressed.
     pub fn shift(&self) -> bool {
         self.contains(Modifiers::SHIFT)
@@ -53,3 +203,465 @@ impl Modifiers {
         self.contains(Modifiers::META)
     }
 }
+
+/// Modifiers are serialized as a string of flag names separated by `|`,
+/// for example `"CONTROL | SHIFT"`.
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Modifiers {
+    fn schema_name() -> alloc::borrow::Cow<'static, str> {
+        "Modifiers".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({ "type": "string" })
+    }
+}
+
+/// Parse modifiers from a list of names separated by `+`, like `Ctrl+Shift`.
+///
+/// The names are matched ignoring ASCII case. Besides the names from the
+/// specification (e.g. `Control`, `AltGraph`) the following common names are
+/// accepted: `Ctrl`, `AltGr`, `Option` for alt and `Cmd`, `Command`, `Super`
+/// and `Win` for meta. An empty string is parsed as no modifiers.
+impl FromStr for Modifiers {
+    type Err = UnrecognizedModifierError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut modifiers = Modifiers::empty();
+        if s.is_empty() {
+            return Ok(modifiers);
+        }
+        for name in s.split('+') {
+            let modifier = [
+                ("Alt", Modifiers::ALT),
+                ("Option", Modifiers::ALT),
+                ("AltGraph", Modifiers::ALT_GRAPH),
+                ("AltGr", Modifiers::ALT_GRAPH),
+                ("CapsLock", Modifiers::CAPS_LOCK),
+                ("Control", Modifiers::CONTROL),
+                ("Ctrl", Modifiers::CONTROL),
+                ("Fn", Modifiers::FN),
+                ("FnLock", Modifiers::FN_LOCK),
+                ("Meta", Modifiers::META),
+                ("Cmd", Modifiers::META),
+                ("Command", Modifiers::META),
+                ("Super", Modifiers::META),
+                ("Win", Modifiers::META),
+                ("NumLock", Modifiers::NUM_LOCK),
+                ("ScrollLock", Modifiers::SCROLL_LOCK),
+                ("Shift", Modifiers::SHIFT),
+                ("Symbol", Modifiers::SYMBOL),
+                ("SymbolLock", Modifiers::SYMBOL_LOCK),
+            ]
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|&(_, modifier)| modifier)
+            .ok_or_else(|| UnrecognizedModifierError { input: name.into() })?;
+            modifiers |= modifier;
+        }
+        Ok(modifiers)
+    }
+}
+
+/// Format modifiers with their names from the specification separated by `+`,
+/// like `Control+Shift`.
+///
+/// The output can be parsed again with [`FromStr`]. No modifiers are
+/// formatted as the empty string. The deprecated [`Modifiers::HYPER`] and
+/// [`Modifiers::SUPER`] flags are not written.
+///
+/// ```rust
+/// # use keyboard_types::Modifiers;
+/// let modifiers = Modifiers::SHIFT | Modifiers::CONTROL;
+/// assert_eq!(modifiers.to_string(), "Control+Shift");
+/// ```
+impl fmt::Display for Modifiers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Names(*self).fmt(f)
+    }
+}
+
+/// Parse from string error, returned when a name does not match any modifier.
+#[derive(Clone, Debug)]
+pub struct UnrecognizedModifierError {
+    input: Box<str>,
+}
+
+impl UnrecognizedModifierError {
+    /// The modifier name that failed to parse.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl fmt::Display for UnrecognizedModifierError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Unrecognized modifier: {:?}", self.input)
+    }
+}
+
+impl Error for UnrecognizedModifierError {}
+
+/// The platform conventions used by [`Modifiers::display_symbols`].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum Platform {
+    /// Apple platforms, using symbols like `⌘`.
+    MacOs,
+    /// All other platforms, using words like `Ctrl`.
+    Other,
+}
+
+struct DisplaySymbols {
+    modifiers: Modifiers,
+    platform: Platform,
+}
+
+impl fmt::Display for DisplaySymbols {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let names = [
+            (Modifiers::CONTROL, "⌃", "Ctrl"),
+            (Modifiers::ALT, "⌥", "Alt"),
+            (Modifiers::SHIFT, "⇧", "Shift"),
+            (Modifiers::META, "⌘", "Meta"),
+        ];
+        let mut first = true;
+        for (modifier, symbol, word) in names {
+            if !self.modifiers.contains(modifier) {
+                continue;
+            }
+            match self.platform {
+                Platform::MacOs => f.write_str(symbol)?,
+                Platform::Other => {
+                    if !first {
+                        f.write_str("+")?;
+                    }
+                    f.write_str(word)?;
+                }
+            }
+            first = false;
+        }
+        Ok(())
+    }
+}
+
+/// The modifiers with their names from the specification, in display order.
+const NAMES: [(Modifiers, &str); 12] = [
+    (Modifiers::CONTROL, "Control"),
+    (Modifiers::ALT, "Alt"),
+    (Modifiers::ALT_GRAPH, "AltGraph"),
+    (Modifiers::SHIFT, "Shift"),
+    (Modifiers::META, "Meta"),
+    (Modifiers::FN, "Fn"),
+    (Modifiers::SYMBOL, "Symbol"),
+    (Modifiers::CAPS_LOCK, "CapsLock"),
+    (Modifiers::FN_LOCK, "FnLock"),
+    (Modifiers::NUM_LOCK, "NumLock"),
+    (Modifiers::SCROLL_LOCK, "ScrollLock"),
+    (Modifiers::SYMBOL_LOCK, "SymbolLock"),
+];
+
+/// Formats modifiers with their names from the specification, like `Control+Alt`.
+struct Names(Modifiers);
+
+impl fmt::Display for Names {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut first = true;
+        for (modifier, name) in NAMES {
+            if self.0.contains(modifier) {
+                if !first {
+                    f.write_str("+")?;
+                }
+                f.write_str(name)?;
+                first = false;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Serialize [`Modifiers`] as an array of names, like `["Control","Shift"]`.
+///
+/// Use it with `#[serde(with = "keyboard_types::modifiers::serde_array")]`.
+/// The names are the ones from the specification and are matched exactly
+/// when deserializing. The deprecated `HYPER` and `SUPER` flags are skipped.
+///
+/// ```rust
+/// use keyboard_types::Modifiers;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Binding {
+///     #[serde(with = "keyboard_types::modifiers::serde_array")]
+///     modifiers: Modifiers,
+/// }
+///
+/// let binding = Binding { modifiers: Modifiers::CONTROL | Modifiers::SHIFT };
+/// let json = serde_json::to_string(&binding).unwrap();
+/// assert_eq!(json, r#"{"modifiers":["Control","Shift"]}"#);
+/// ```
+#[cfg(feature = "serde")]
+pub mod serde_array {
+    use super::{Modifiers, NAMES};
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use serde::de::{self, Deserialize, Deserializer};
+    use serde::ser::{SerializeSeq, Serializer};
+
+    /// Serialize the modifiers as an array of names.
+    pub fn serialize<S: Serializer>(modifiers: &Modifiers, s: S) -> Result<S::Ok, S::Error> {
+        let mut seq = s.serialize_seq(None)?;
+        for (modifier, name) in NAMES {
+            if modifiers.contains(modifier) {
+                seq.serialize_element(name)?;
+            }
+        }
+        seq.end()
+    }
+
+    /// Deserialize the modifiers from an array of names.
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Modifiers, D::Error> {
+        let mut modifiers = Modifiers::empty();
+        for name in Vec::<String>::deserialize(d)? {
+            let (modifier, _) = NAMES
+                .iter()
+                .find(|(_, n)| *n == name)
+                .ok_or_else(|| de::Error::unknown_variant(&name, &EXPECTED))?;
+            modifiers |= *modifier;
+        }
+        Ok(modifiers)
+    }
+
+    const EXPECTED: [&str; 12] = {
+        let mut names = [""; 12];
+        let mut i = 0;
+        while i < NAMES.len() {
+            names[i] = NAMES[i].1;
+            i += 1;
+        }
+        names
+    };
+}
+
+/// Error returned by [`Modifiers::from_bits_checked`] if unknown bits are set.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvalidModifierBits(u32);
+
+impl InvalidModifierBits {
+    /// The bits that do not correspond to any modifier.
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+}
+
+impl fmt::Display for InvalidModifierBits {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid modifier bits: {:#x}", self.0)
+    }
+}
+
+impl Error for InvalidModifierBits {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_bits_checked() {
+        let bits = (Modifiers::SHIFT | Modifiers::CONTROL).bits();
+        assert_eq!(
+            Modifiers::from_bits_checked(bits),
+            Ok(Modifiers::SHIFT | Modifiers::CONTROL)
+        );
+        let err = Modifiers::from_bits_checked(bits | 0x10000).unwrap_err();
+        assert_eq!(err.bits(), 0x10000);
+    }
+
+    #[test]
+    fn display_without_alloc() {
+        use crate::{Code, NamedKey};
+        use core::fmt::Write;
+
+        /// A fixed size buffer, to make sure formatting does not allocate.
+        struct Buffer {
+            bytes: [u8; 32],
+            len: usize,
+        }
+
+        impl Write for Buffer {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                let end = self.len + s.len();
+                self.bytes
+                    .get_mut(self.len..end)
+                    .ok_or(fmt::Error)?
+                    .copy_from_slice(s.as_bytes());
+                self.len = end;
+                Ok(())
+            }
+        }
+
+        let mut buffer = Buffer {
+            bytes: [0; 32],
+            len: 0,
+        };
+        let modifiers = Modifiers::CONTROL | Modifiers::SHIFT;
+        write!(buffer, "{} {} {}", modifiers, Code::KeyS, NamedKey::Enter).unwrap();
+        assert_eq!(&buffer.bytes[..buffer.len], b"Control+Shift KeyS Enter");
+
+        buffer.len = 0;
+        write!(buffer, "{}", modifiers.display_symbols(Platform::Other)).unwrap();
+        assert_eq!(&buffer.bytes[..buffer.len], b"Ctrl+Shift");
+    }
+
+    #[test]
+    fn all_of() {
+        let modifiers = [Modifiers::CONTROL, Modifiers::SHIFT, Modifiers::CONTROL];
+        assert_eq!(
+            Modifiers::all_of(&modifiers),
+            Modifiers::CONTROL | Modifiers::SHIFT
+        );
+        assert_eq!(Modifiers::all_of(&[]), Modifiers::empty());
+    }
+
+    #[test]
+    fn chord_only() {
+        let modifiers = Modifiers::CONTROL | Modifiers::CAPS_LOCK | Modifiers::NUM_LOCK;
+        assert_eq!(modifiers.chord_only(), Modifiers::CONTROL);
+        assert_eq!(
+            modifiers.chord_only(),
+            (Modifiers::CONTROL | Modifiers::NUM_LOCK).chord_only()
+        );
+    }
+
+    #[test]
+    fn shortcut_only() {
+        let modifiers = Modifiers::CONTROL | Modifiers::ALT_GRAPH | Modifiers::CAPS_LOCK;
+        assert_eq!(modifiers.shortcut_only(), Modifiers::CONTROL);
+        assert_eq!(
+            modifiers.chord_only(),
+            Modifiers::CONTROL | Modifiers::ALT_GRAPH
+        );
+    }
+
+    #[test]
+    fn count() {
+        assert_eq!(Modifiers::empty().count(), 0);
+        assert_eq!(Modifiers::NUM_LOCK.count(), 0);
+        assert_eq!((Modifiers::CONTROL | Modifiers::CAPS_LOCK).count(), 1);
+        let modifiers = Modifiers::CONTROL | Modifiers::SHIFT | Modifiers::ALT;
+        assert_eq!(modifiers.count(), 3);
+    }
+
+    #[test]
+    fn display_symbols() {
+        use alloc::string::ToString;
+
+        let modifiers = Modifiers::META | Modifiers::SHIFT | Modifiers::ALT | Modifiers::CONTROL;
+        assert_eq!(
+            modifiers.display_symbols(Platform::MacOs).to_string(),
+            "⌃⌥⇧⌘"
+        );
+        assert_eq!(
+            modifiers.display_symbols(Platform::Other).to_string(),
+            "Ctrl+Alt+Shift+Meta"
+        );
+        let modifiers = Modifiers::SHIFT | Modifiers::CONTROL | Modifiers::NUM_LOCK;
+        assert_eq!(modifiers.display_symbols(Platform::MacOs).to_string(), "⌃⇧");
+        assert_eq!(
+            modifiers.display_symbols(Platform::Other).to_string(),
+            "Ctrl+Shift"
+        );
+        assert_eq!(
+            Modifiers::empty()
+                .display_symbols(Platform::Other)
+                .to_string(),
+            ""
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            "Ctrl+Shift".parse::<Modifiers>().ok(),
+            Some(Modifiers::CONTROL | Modifiers::SHIFT)
+        );
+        assert_eq!("cmd".parse::<Modifiers>().ok(), Some(Modifiers::META));
+        assert_eq!("".parse::<Modifiers>().ok(), Some(Modifiers::empty()));
+        let err = "Ctrl+Hyperr".parse::<Modifiers>().unwrap_err();
+        assert_eq!(err.input(), "Hyperr");
+    }
+
+    #[test]
+    fn display() {
+        use alloc::string::ToString;
+
+        assert_eq!(Modifiers::empty().to_string(), "");
+        let modifiers = Modifiers::SHIFT | Modifiers::ALT_GRAPH | Modifiers::CAPS_LOCK;
+        assert_eq!(modifiers.to_string(), "AltGraph+Shift+CapsLock");
+        assert_eq!(
+            modifiers.to_string().parse::<Modifiers>().ok(),
+            Some(modifiers)
+        );
+    }
+
+    #[test]
+    fn describe_mismatch() {
+        assert_eq!(
+            Modifiers::describe_mismatch(Modifiers::CONTROL, Modifiers::ALT),
+            "expected Control, but Alt was held"
+        );
+        assert_eq!(
+            Modifiers::describe_mismatch(Modifiers::CONTROL | Modifiers::SHIFT, Modifiers::CONTROL),
+            "expected Shift, but it was not held"
+        );
+        assert_eq!(
+            Modifiers::describe_mismatch(Modifiers::META, Modifiers::META | Modifiers::ALT),
+            "expected no Alt, but it was held"
+        );
+        assert_eq!(
+            Modifiers::describe_mismatch(Modifiers::META, Modifiers::META),
+            ""
+        );
+    }
+
+    #[test]
+    fn superset_and_disjoint() {
+        let held = Modifiers::CONTROL | Modifiers::SHIFT;
+        assert!(held.is_superset_of(Modifiers::CONTROL));
+        assert!(held.is_superset_of(held));
+        assert!(held.is_superset_of(Modifiers::empty()));
+        assert!(!held.is_superset_of(Modifiers::CONTROL | Modifiers::ALT));
+
+        assert!(held.is_disjoint(Modifiers::ALT | Modifiers::META));
+        assert!(held.is_disjoint(Modifiers::empty()));
+        assert!(!held.is_disjoint(Modifiers::SHIFT | Modifiers::ALT));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_array() {
+        use alloc::string::ToString;
+
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Binding {
+            #[serde(with = "super::serde_array")]
+            modifiers: Modifiers,
+        }
+
+        let binding = Binding {
+            modifiers: Modifiers::SHIFT | Modifiers::CONTROL | Modifiers::CAPS_LOCK,
+        };
+        let json = r#"{"modifiers":["Control","Shift","CapsLock"]}"#;
+        assert_eq!(serde_json::to_string(&binding).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Binding>(json).unwrap(), binding);
+        let empty = r#"{"modifiers":[]}"#;
+        assert_eq!(
+            serde_json::from_str::<Binding>(empty).unwrap().modifiers,
+            Modifiers::empty()
+        );
+        let json = r#"{"modifiers":["Ctrl"]}"#;
+        let error = serde_json::from_str::<Binding>(json).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("expected one of `Control`, `Alt`, `AltGraph`, `Shift`"));
+    }
+}
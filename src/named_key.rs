@@ -4,6 +4,7 @@
 #![allow(clippy::doc_markdown)]
 #![allow(deprecated)]
 
+use alloc::boxed::Box;
 use core::fmt::{self, Display};
 use core::str::FromStr;
 #[cfg(not(feature = "std"))]
@@ -20,6 +21,7 @@ use std::error::Error;
 /// `Key::Character(" ")` instead.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub enum NamedKey {
     /// This key value is used when an implementation is unable to
@@ -675,320 +677,642 @@ pub enum NamedKey {
 }
 
 
-impl Display for NamedKey {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+impl NamedKey {
+    /// The name of the key, as used by the [`Display`] implementation.
+    pub const fn as_str(&self) -> &'static str {
         use self::NamedKey::*;
         match *self {
-            Unidentified => f.write_str("Unidentified"),
-            Alt => f.write_str("Alt"),
-            AltGraph => f.write_str("AltGraph"),
-            CapsLock => f.write_str("CapsLock"),
-            Control => f.write_str("Control"),
-            Fn => f.write_str("Fn"),
-            FnLock => f.write_str("FnLock"),
-            Meta => f.write_str("Meta"),
-            NumLock => f.write_str("NumLock"),
-            ScrollLock => f.write_str("ScrollLock"),
-            Shift => f.write_str("Shift"),
-            Symbol => f.write_str("Symbol"),
-            SymbolLock => f.write_str("SymbolLock"),
-            Hyper => f.write_str("Hyper"),
-            Super => f.write_str("Super"),
-            Enter => f.write_str("Enter"),
-            Tab => f.write_str("Tab"),
-            ArrowDown => f.write_str("ArrowDown"),
-            ArrowLeft => f.write_str("ArrowLeft"),
-            ArrowRight => f.write_str("ArrowRight"),
-            ArrowUp => f.write_str("ArrowUp"),
-            End => f.write_str("End"),
-            Home => f.write_str("Home"),
-            PageDown => f.write_str("PageDown"),
-            PageUp => f.write_str("PageUp"),
-            Backspace => f.write_str("Backspace"),
-            Clear => f.write_str("Clear"),
-            Copy => f.write_str("Copy"),
-            CrSel => f.write_str("CrSel"),
-            Cut => f.write_str("Cut"),
-            Delete => f.write_str("Delete"),
-            EraseEof => f.write_str("EraseEof"),
-            ExSel => f.write_str("ExSel"),
-            Insert => f.write_str("Insert"),
-            Paste => f.write_str("Paste"),
-            Redo => f.write_str("Redo"),
-            Undo => f.write_str("Undo"),
-            Accept => f.write_str("Accept"),
-            Again => f.write_str("Again"),
-            Attn => f.write_str("Attn"),
-            Cancel => f.write_str("Cancel"),
-            ContextMenu => f.write_str("ContextMenu"),
-            Escape => f.write_str("Escape"),
-            Execute => f.write_str("Execute"),
-            Find => f.write_str("Find"),
-            Help => f.write_str("Help"),
-            Pause => f.write_str("Pause"),
-            Play => f.write_str("Play"),
-            Props => f.write_str("Props"),
-            Select => f.write_str("Select"),
-            ZoomIn => f.write_str("ZoomIn"),
-            ZoomOut => f.write_str("ZoomOut"),
-            BrightnessDown => f.write_str("BrightnessDown"),
-            BrightnessUp => f.write_str("BrightnessUp"),
-            Eject => f.write_str("Eject"),
-            LogOff => f.write_str("LogOff"),
-            Power => f.write_str("Power"),
-            PowerOff => f.write_str("PowerOff"),
-            PrintScreen => f.write_str("PrintScreen"),
-            Hibernate => f.write_str("Hibernate"),
-            Standby => f.write_str("Standby"),
-            WakeUp => f.write_str("WakeUp"),
-            AllCandidates => f.write_str("AllCandidates"),
-            Alphanumeric => f.write_str("Alphanumeric"),
-            CodeInput => f.write_str("CodeInput"),
-            Compose => f.write_str("Compose"),
-            Convert => f.write_str("Convert"),
-            Dead => f.write_str("Dead"),
-            FinalMode => f.write_str("FinalMode"),
-            GroupFirst => f.write_str("GroupFirst"),
-            GroupLast => f.write_str("GroupLast"),
-            GroupNext => f.write_str("GroupNext"),
-            GroupPrevious => f.write_str("GroupPrevious"),
-            ModeChange => f.write_str("ModeChange"),
-            NextCandidate => f.write_str("NextCandidate"),
-            NonConvert => f.write_str("NonConvert"),
-            PreviousCandidate => f.write_str("PreviousCandidate"),
-            Process => f.write_str("Process"),
-            SingleCandidate => f.write_str("SingleCandidate"),
-            HangulMode => f.write_str("HangulMode"),
-            HanjaMode => f.write_str("HanjaMode"),
-            JunjaMode => f.write_str("JunjaMode"),
-            Eisu => f.write_str("Eisu"),
-            Hankaku => f.write_str("Hankaku"),
-            Hiragana => f.write_str("Hiragana"),
-            HiraganaKatakana => f.write_str("HiraganaKatakana"),
-            KanaMode => f.write_str("KanaMode"),
-            KanjiMode => f.write_str("KanjiMode"),
-            Katakana => f.write_str("Katakana"),
-            Romaji => f.write_str("Romaji"),
-            Zenkaku => f.write_str("Zenkaku"),
-            ZenkakuHankaku => f.write_str("ZenkakuHankaku"),
-            Soft1 => f.write_str("Soft1"),
-            Soft2 => f.write_str("Soft2"),
-            Soft3 => f.write_str("Soft3"),
-            Soft4 => f.write_str("Soft4"),
-            ChannelDown => f.write_str("ChannelDown"),
-            ChannelUp => f.write_str("ChannelUp"),
-            Close => f.write_str("Close"),
-            MailForward => f.write_str("MailForward"),
-            MailReply => f.write_str("MailReply"),
-            MailSend => f.write_str("MailSend"),
-            MediaClose => f.write_str("MediaClose"),
-            MediaFastForward => f.write_str("MediaFastForward"),
-            MediaPause => f.write_str("MediaPause"),
-            MediaPlay => f.write_str("MediaPlay"),
-            MediaPlayPause => f.write_str("MediaPlayPause"),
-            MediaRecord => f.write_str("MediaRecord"),
-            MediaRewind => f.write_str("MediaRewind"),
-            MediaStop => f.write_str("MediaStop"),
-            MediaTrackNext => f.write_str("MediaTrackNext"),
-            MediaTrackPrevious => f.write_str("MediaTrackPrevious"),
-            New => f.write_str("New"),
-            Open => f.write_str("Open"),
-            Print => f.write_str("Print"),
-            Save => f.write_str("Save"),
-            SpellCheck => f.write_str("SpellCheck"),
-            Key11 => f.write_str("Key11"),
-            Key12 => f.write_str("Key12"),
-            AudioBalanceLeft => f.write_str("AudioBalanceLeft"),
-            AudioBalanceRight => f.write_str("AudioBalanceRight"),
-            AudioBassBoostDown => f.write_str("AudioBassBoostDown"),
-            AudioBassBoostToggle => f.write_str("AudioBassBoostToggle"),
-            AudioBassBoostUp => f.write_str("AudioBassBoostUp"),
-            AudioFaderFront => f.write_str("AudioFaderFront"),
-            AudioFaderRear => f.write_str("AudioFaderRear"),
-            AudioSurroundModeNext => f.write_str("AudioSurroundModeNext"),
-            AudioTrebleDown => f.write_str("AudioTrebleDown"),
-            AudioTrebleUp => f.write_str("AudioTrebleUp"),
-            AudioVolumeDown => f.write_str("AudioVolumeDown"),
-            AudioVolumeUp => f.write_str("AudioVolumeUp"),
-            AudioVolumeMute => f.write_str("AudioVolumeMute"),
-            MicrophoneToggle => f.write_str("MicrophoneToggle"),
-            MicrophoneVolumeDown => f.write_str("MicrophoneVolumeDown"),
-            MicrophoneVolumeUp => f.write_str("MicrophoneVolumeUp"),
-            MicrophoneVolumeMute => f.write_str("MicrophoneVolumeMute"),
-            SpeechCorrectionList => f.write_str("SpeechCorrectionList"),
-            SpeechInputToggle => f.write_str("SpeechInputToggle"),
-            LaunchApplication1 => f.write_str("LaunchApplication1"),
-            LaunchApplication2 => f.write_str("LaunchApplication2"),
-            LaunchCalendar => f.write_str("LaunchCalendar"),
-            LaunchContacts => f.write_str("LaunchContacts"),
-            LaunchMail => f.write_str("LaunchMail"),
-            LaunchMediaPlayer => f.write_str("LaunchMediaPlayer"),
-            LaunchMusicPlayer => f.write_str("LaunchMusicPlayer"),
-            LaunchPhone => f.write_str("LaunchPhone"),
-            LaunchScreenSaver => f.write_str("LaunchScreenSaver"),
-            LaunchSpreadsheet => f.write_str("LaunchSpreadsheet"),
-            LaunchWebBrowser => f.write_str("LaunchWebBrowser"),
-            LaunchWebCam => f.write_str("LaunchWebCam"),
-            LaunchWordProcessor => f.write_str("LaunchWordProcessor"),
-            BrowserBack => f.write_str("BrowserBack"),
-            BrowserFavorites => f.write_str("BrowserFavorites"),
-            BrowserForward => f.write_str("BrowserForward"),
-            BrowserHome => f.write_str("BrowserHome"),
-            BrowserRefresh => f.write_str("BrowserRefresh"),
-            BrowserSearch => f.write_str("BrowserSearch"),
-            BrowserStop => f.write_str("BrowserStop"),
-            AppSwitch => f.write_str("AppSwitch"),
-            Call => f.write_str("Call"),
-            Camera => f.write_str("Camera"),
-            CameraFocus => f.write_str("CameraFocus"),
-            EndCall => f.write_str("EndCall"),
-            GoBack => f.write_str("GoBack"),
-            GoHome => f.write_str("GoHome"),
-            HeadsetHook => f.write_str("HeadsetHook"),
-            LastNumberRedial => f.write_str("LastNumberRedial"),
-            Notification => f.write_str("Notification"),
-            MannerMode => f.write_str("MannerMode"),
-            VoiceDial => f.write_str("VoiceDial"),
-            TV => f.write_str("TV"),
-            TV3DMode => f.write_str("TV3DMode"),
-            TVAntennaCable => f.write_str("TVAntennaCable"),
-            TVAudioDescription => f.write_str("TVAudioDescription"),
-            TVAudioDescriptionMixDown => f.write_str("TVAudioDescriptionMixDown"),
-            TVAudioDescriptionMixUp => f.write_str("TVAudioDescriptionMixUp"),
-            TVContentsMenu => f.write_str("TVContentsMenu"),
-            TVDataService => f.write_str("TVDataService"),
-            TVInput => f.write_str("TVInput"),
-            TVInputComponent1 => f.write_str("TVInputComponent1"),
-            TVInputComponent2 => f.write_str("TVInputComponent2"),
-            TVInputComposite1 => f.write_str("TVInputComposite1"),
-            TVInputComposite2 => f.write_str("TVInputComposite2"),
-            TVInputHDMI1 => f.write_str("TVInputHDMI1"),
-            TVInputHDMI2 => f.write_str("TVInputHDMI2"),
-            TVInputHDMI3 => f.write_str("TVInputHDMI3"),
-            TVInputHDMI4 => f.write_str("TVInputHDMI4"),
-            TVInputVGA1 => f.write_str("TVInputVGA1"),
-            TVMediaContext => f.write_str("TVMediaContext"),
-            TVNetwork => f.write_str("TVNetwork"),
-            TVNumberEntry => f.write_str("TVNumberEntry"),
-            TVPower => f.write_str("TVPower"),
-            TVRadioService => f.write_str("TVRadioService"),
-            TVSatellite => f.write_str("TVSatellite"),
-            TVSatelliteBS => f.write_str("TVSatelliteBS"),
-            TVSatelliteCS => f.write_str("TVSatelliteCS"),
-            TVSatelliteToggle => f.write_str("TVSatelliteToggle"),
-            TVTerrestrialAnalog => f.write_str("TVTerrestrialAnalog"),
-            TVTerrestrialDigital => f.write_str("TVTerrestrialDigital"),
-            TVTimer => f.write_str("TVTimer"),
-            AVRInput => f.write_str("AVRInput"),
-            AVRPower => f.write_str("AVRPower"),
-            ColorF0Red => f.write_str("ColorF0Red"),
-            ColorF1Green => f.write_str("ColorF1Green"),
-            ColorF2Yellow => f.write_str("ColorF2Yellow"),
-            ColorF3Blue => f.write_str("ColorF3Blue"),
-            ColorF4Grey => f.write_str("ColorF4Grey"),
-            ColorF5Brown => f.write_str("ColorF5Brown"),
-            ClosedCaptionToggle => f.write_str("ClosedCaptionToggle"),
-            Dimmer => f.write_str("Dimmer"),
-            DisplaySwap => f.write_str("DisplaySwap"),
-            DVR => f.write_str("DVR"),
-            Exit => f.write_str("Exit"),
-            FavoriteClear0 => f.write_str("FavoriteClear0"),
-            FavoriteClear1 => f.write_str("FavoriteClear1"),
-            FavoriteClear2 => f.write_str("FavoriteClear2"),
-            FavoriteClear3 => f.write_str("FavoriteClear3"),
-            FavoriteRecall0 => f.write_str("FavoriteRecall0"),
-            FavoriteRecall1 => f.write_str("FavoriteRecall1"),
-            FavoriteRecall2 => f.write_str("FavoriteRecall2"),
-            FavoriteRecall3 => f.write_str("FavoriteRecall3"),
-            FavoriteStore0 => f.write_str("FavoriteStore0"),
-            FavoriteStore1 => f.write_str("FavoriteStore1"),
-            FavoriteStore2 => f.write_str("FavoriteStore2"),
-            FavoriteStore3 => f.write_str("FavoriteStore3"),
-            Guide => f.write_str("Guide"),
-            GuideNextDay => f.write_str("GuideNextDay"),
-            GuidePreviousDay => f.write_str("GuidePreviousDay"),
-            Info => f.write_str("Info"),
-            InstantReplay => f.write_str("InstantReplay"),
-            Link => f.write_str("Link"),
-            ListProgram => f.write_str("ListProgram"),
-            LiveContent => f.write_str("LiveContent"),
-            Lock => f.write_str("Lock"),
-            MediaApps => f.write_str("MediaApps"),
-            MediaAudioTrack => f.write_str("MediaAudioTrack"),
-            MediaLast => f.write_str("MediaLast"),
-            MediaSkipBackward => f.write_str("MediaSkipBackward"),
-            MediaSkipForward => f.write_str("MediaSkipForward"),
-            MediaStepBackward => f.write_str("MediaStepBackward"),
-            MediaStepForward => f.write_str("MediaStepForward"),
-            MediaTopMenu => f.write_str("MediaTopMenu"),
-            NavigateIn => f.write_str("NavigateIn"),
-            NavigateNext => f.write_str("NavigateNext"),
-            NavigateOut => f.write_str("NavigateOut"),
-            NavigatePrevious => f.write_str("NavigatePrevious"),
-            NextFavoriteChannel => f.write_str("NextFavoriteChannel"),
-            NextUserProfile => f.write_str("NextUserProfile"),
-            OnDemand => f.write_str("OnDemand"),
-            Pairing => f.write_str("Pairing"),
-            PinPDown => f.write_str("PinPDown"),
-            PinPMove => f.write_str("PinPMove"),
-            PinPToggle => f.write_str("PinPToggle"),
-            PinPUp => f.write_str("PinPUp"),
-            PlaySpeedDown => f.write_str("PlaySpeedDown"),
-            PlaySpeedReset => f.write_str("PlaySpeedReset"),
-            PlaySpeedUp => f.write_str("PlaySpeedUp"),
-            RandomToggle => f.write_str("RandomToggle"),
-            RcLowBattery => f.write_str("RcLowBattery"),
-            RecordSpeedNext => f.write_str("RecordSpeedNext"),
-            RfBypass => f.write_str("RfBypass"),
-            ScanChannelsToggle => f.write_str("ScanChannelsToggle"),
-            ScreenModeNext => f.write_str("ScreenModeNext"),
-            Settings => f.write_str("Settings"),
-            SplitScreenToggle => f.write_str("SplitScreenToggle"),
-            STBInput => f.write_str("STBInput"),
-            STBPower => f.write_str("STBPower"),
-            Subtitle => f.write_str("Subtitle"),
-            Teletext => f.write_str("Teletext"),
-            VideoModeNext => f.write_str("VideoModeNext"),
-            Wink => f.write_str("Wink"),
-            ZoomToggle => f.write_str("ZoomToggle"),
-            F1 => f.write_str("F1"),
-            F2 => f.write_str("F2"),
-            F3 => f.write_str("F3"),
-            F4 => f.write_str("F4"),
-            F5 => f.write_str("F5"),
-            F6 => f.write_str("F6"),
-            F7 => f.write_str("F7"),
-            F8 => f.write_str("F8"),
-            F9 => f.write_str("F9"),
-            F10 => f.write_str("F10"),
-            F11 => f.write_str("F11"),
-            F12 => f.write_str("F12"),
-            F13 => f.write_str("F13"),
-            F14 => f.write_str("F14"),
-            F15 => f.write_str("F15"),
-            F16 => f.write_str("F16"),
-            F17 => f.write_str("F17"),
-            F18 => f.write_str("F18"),
-            F19 => f.write_str("F19"),
-            F20 => f.write_str("F20"),
-            F21 => f.write_str("F21"),
-            F22 => f.write_str("F22"),
-            F23 => f.write_str("F23"),
-            F24 => f.write_str("F24"),
-            F25 => f.write_str("F25"),
-            F26 => f.write_str("F26"),
-            F27 => f.write_str("F27"),
-            F28 => f.write_str("F28"),
-            F29 => f.write_str("F29"),
-            F30 => f.write_str("F30"),
-            F31 => f.write_str("F31"),
-            F32 => f.write_str("F32"),
-            F33 => f.write_str("F33"),
-            F34 => f.write_str("F34"),
-            F35 => f.write_str("F35"),
+            Unidentified => "Unidentified",
+            Alt => "Alt",
+            AltGraph => "AltGraph",
+            CapsLock => "CapsLock",
+            Control => "Control",
+            Fn => "Fn",
+            FnLock => "FnLock",
+            Meta => "Meta",
+            NumLock => "NumLock",
+            ScrollLock => "ScrollLock",
+            Shift => "Shift",
+            Symbol => "Symbol",
+            SymbolLock => "SymbolLock",
+            Hyper => "Hyper",
+            Super => "Super",
+            Enter => "Enter",
+            Tab => "Tab",
+            ArrowDown => "ArrowDown",
+            ArrowLeft => "ArrowLeft",
+            ArrowRight => "ArrowRight",
+            ArrowUp => "ArrowUp",
+            End => "End",
+            Home => "Home",
+            PageDown => "PageDown",
+            PageUp => "PageUp",
+            Backspace => "Backspace",
+            Clear => "Clear",
+            Copy => "Copy",
+            CrSel => "CrSel",
+            Cut => "Cut",
+            Delete => "Delete",
+            EraseEof => "EraseEof",
+            ExSel => "ExSel",
+            Insert => "Insert",
+            Paste => "Paste",
+            Redo => "Redo",
+            Undo => "Undo",
+            Accept => "Accept",
+            Again => "Again",
+            Attn => "Attn",
+            Cancel => "Cancel",
+            ContextMenu => "ContextMenu",
+            Escape => "Escape",
+            Execute => "Execute",
+            Find => "Find",
+            Help => "Help",
+            Pause => "Pause",
+            Play => "Play",
+            Props => "Props",
+            Select => "Select",
+            ZoomIn => "ZoomIn",
+            ZoomOut => "ZoomOut",
+            BrightnessDown => "BrightnessDown",
+            BrightnessUp => "BrightnessUp",
+            Eject => "Eject",
+            LogOff => "LogOff",
+            Power => "Power",
+            PowerOff => "PowerOff",
+            PrintScreen => "PrintScreen",
+            Hibernate => "Hibernate",
+            Standby => "Standby",
+            WakeUp => "WakeUp",
+            AllCandidates => "AllCandidates",
+            Alphanumeric => "Alphanumeric",
+            CodeInput => "CodeInput",
+            Compose => "Compose",
+            Convert => "Convert",
+            Dead => "Dead",
+            FinalMode => "FinalMode",
+            GroupFirst => "GroupFirst",
+            GroupLast => "GroupLast",
+            GroupNext => "GroupNext",
+            GroupPrevious => "GroupPrevious",
+            ModeChange => "ModeChange",
+            NextCandidate => "NextCandidate",
+            NonConvert => "NonConvert",
+            PreviousCandidate => "PreviousCandidate",
+            Process => "Process",
+            SingleCandidate => "SingleCandidate",
+            HangulMode => "HangulMode",
+            HanjaMode => "HanjaMode",
+            JunjaMode => "JunjaMode",
+            Eisu => "Eisu",
+            Hankaku => "Hankaku",
+            Hiragana => "Hiragana",
+            HiraganaKatakana => "HiraganaKatakana",
+            KanaMode => "KanaMode",
+            KanjiMode => "KanjiMode",
+            Katakana => "Katakana",
+            Romaji => "Romaji",
+            Zenkaku => "Zenkaku",
+            ZenkakuHankaku => "ZenkakuHankaku",
+            Soft1 => "Soft1",
+            Soft2 => "Soft2",
+            Soft3 => "Soft3",
+            Soft4 => "Soft4",
+            ChannelDown => "ChannelDown",
+            ChannelUp => "ChannelUp",
+            Close => "Close",
+            MailForward => "MailForward",
+            MailReply => "MailReply",
+            MailSend => "MailSend",
+            MediaClose => "MediaClose",
+            MediaFastForward => "MediaFastForward",
+            MediaPause => "MediaPause",
+            MediaPlay => "MediaPlay",
+            MediaPlayPause => "MediaPlayPause",
+            MediaRecord => "MediaRecord",
+            MediaRewind => "MediaRewind",
+            MediaStop => "MediaStop",
+            MediaTrackNext => "MediaTrackNext",
+            MediaTrackPrevious => "MediaTrackPrevious",
+            New => "New",
+            Open => "Open",
+            Print => "Print",
+            Save => "Save",
+            SpellCheck => "SpellCheck",
+            Key11 => "Key11",
+            Key12 => "Key12",
+            AudioBalanceLeft => "AudioBalanceLeft",
+            AudioBalanceRight => "AudioBalanceRight",
+            AudioBassBoostDown => "AudioBassBoostDown",
+            AudioBassBoostToggle => "AudioBassBoostToggle",
+            AudioBassBoostUp => "AudioBassBoostUp",
+            AudioFaderFront => "AudioFaderFront",
+            AudioFaderRear => "AudioFaderRear",
+            AudioSurroundModeNext => "AudioSurroundModeNext",
+            AudioTrebleDown => "AudioTrebleDown",
+            AudioTrebleUp => "AudioTrebleUp",
+            AudioVolumeDown => "AudioVolumeDown",
+            AudioVolumeUp => "AudioVolumeUp",
+            AudioVolumeMute => "AudioVolumeMute",
+            MicrophoneToggle => "MicrophoneToggle",
+            MicrophoneVolumeDown => "MicrophoneVolumeDown",
+            MicrophoneVolumeUp => "MicrophoneVolumeUp",
+            MicrophoneVolumeMute => "MicrophoneVolumeMute",
+            SpeechCorrectionList => "SpeechCorrectionList",
+            SpeechInputToggle => "SpeechInputToggle",
+            LaunchApplication1 => "LaunchApplication1",
+            LaunchApplication2 => "LaunchApplication2",
+            LaunchCalendar => "LaunchCalendar",
+            LaunchContacts => "LaunchContacts",
+            LaunchMail => "LaunchMail",
+            LaunchMediaPlayer => "LaunchMediaPlayer",
+            LaunchMusicPlayer => "LaunchMusicPlayer",
+            LaunchPhone => "LaunchPhone",
+            LaunchScreenSaver => "LaunchScreenSaver",
+            LaunchSpreadsheet => "LaunchSpreadsheet",
+            LaunchWebBrowser => "LaunchWebBrowser",
+            LaunchWebCam => "LaunchWebCam",
+            LaunchWordProcessor => "LaunchWordProcessor",
+            BrowserBack => "BrowserBack",
+            BrowserFavorites => "BrowserFavorites",
+            BrowserForward => "BrowserForward",
+            BrowserHome => "BrowserHome",
+            BrowserRefresh => "BrowserRefresh",
+            BrowserSearch => "BrowserSearch",
+            BrowserStop => "BrowserStop",
+            AppSwitch => "AppSwitch",
+            Call => "Call",
+            Camera => "Camera",
+            CameraFocus => "CameraFocus",
+            EndCall => "EndCall",
+            GoBack => "GoBack",
+            GoHome => "GoHome",
+            HeadsetHook => "HeadsetHook",
+            LastNumberRedial => "LastNumberRedial",
+            Notification => "Notification",
+            MannerMode => "MannerMode",
+            VoiceDial => "VoiceDial",
+            TV => "TV",
+            TV3DMode => "TV3DMode",
+            TVAntennaCable => "TVAntennaCable",
+            TVAudioDescription => "TVAudioDescription",
+            TVAudioDescriptionMixDown => "TVAudioDescriptionMixDown",
+            TVAudioDescriptionMixUp => "TVAudioDescriptionMixUp",
+            TVContentsMenu => "TVContentsMenu",
+            TVDataService => "TVDataService",
+            TVInput => "TVInput",
+            TVInputComponent1 => "TVInputComponent1",
+            TVInputComponent2 => "TVInputComponent2",
+            TVInputComposite1 => "TVInputComposite1",
+            TVInputComposite2 => "TVInputComposite2",
+            TVInputHDMI1 => "TVInputHDMI1",
+            TVInputHDMI2 => "TVInputHDMI2",
+            TVInputHDMI3 => "TVInputHDMI3",
+            TVInputHDMI4 => "TVInputHDMI4",
+            TVInputVGA1 => "TVInputVGA1",
+            TVMediaContext => "TVMediaContext",
+            TVNetwork => "TVNetwork",
+            TVNumberEntry => "TVNumberEntry",
+            TVPower => "TVPower",
+            TVRadioService => "TVRadioService",
+            TVSatellite => "TVSatellite",
+            TVSatelliteBS => "TVSatelliteBS",
+            TVSatelliteCS => "TVSatelliteCS",
+            TVSatelliteToggle => "TVSatelliteToggle",
+            TVTerrestrialAnalog => "TVTerrestrialAnalog",
+            TVTerrestrialDigital => "TVTerrestrialDigital",
+            TVTimer => "TVTimer",
+            AVRInput => "AVRInput",
+            AVRPower => "AVRPower",
+            ColorF0Red => "ColorF0Red",
+            ColorF1Green => "ColorF1Green",
+            ColorF2Yellow => "ColorF2Yellow",
+            ColorF3Blue => "ColorF3Blue",
+            ColorF4Grey => "ColorF4Grey",
+            ColorF5Brown => "ColorF5Brown",
+            ClosedCaptionToggle => "ClosedCaptionToggle",
+            Dimmer => "Dimmer",
+            DisplaySwap => "DisplaySwap",
+            DVR => "DVR",
+            Exit => "Exit",
+            FavoriteClear0 => "FavoriteClear0",
+            FavoriteClear1 => "FavoriteClear1",
+            FavoriteClear2 => "FavoriteClear2",
+            FavoriteClear3 => "FavoriteClear3",
+            FavoriteRecall0 => "FavoriteRecall0",
+            FavoriteRecall1 => "FavoriteRecall1",
+            FavoriteRecall2 => "FavoriteRecall2",
+            FavoriteRecall3 => "FavoriteRecall3",
+            FavoriteStore0 => "FavoriteStore0",
+            FavoriteStore1 => "FavoriteStore1",
+            FavoriteStore2 => "FavoriteStore2",
+            FavoriteStore3 => "FavoriteStore3",
+            Guide => "Guide",
+            GuideNextDay => "GuideNextDay",
+            GuidePreviousDay => "GuidePreviousDay",
+            Info => "Info",
+            InstantReplay => "InstantReplay",
+            Link => "Link",
+            ListProgram => "ListProgram",
+            LiveContent => "LiveContent",
+            Lock => "Lock",
+            MediaApps => "MediaApps",
+            MediaAudioTrack => "MediaAudioTrack",
+            MediaLast => "MediaLast",
+            MediaSkipBackward => "MediaSkipBackward",
+            MediaSkipForward => "MediaSkipForward",
+            MediaStepBackward => "MediaStepBackward",
+            MediaStepForward => "MediaStepForward",
+            MediaTopMenu => "MediaTopMenu",
+            NavigateIn => "NavigateIn",
+            NavigateNext => "NavigateNext",
+            NavigateOut => "NavigateOut",
+            NavigatePrevious => "NavigatePrevious",
+            NextFavoriteChannel => "NextFavoriteChannel",
+            NextUserProfile => "NextUserProfile",
+            OnDemand => "OnDemand",
+            Pairing => "Pairing",
+            PinPDown => "PinPDown",
+            PinPMove => "PinPMove",
+            PinPToggle => "PinPToggle",
+            PinPUp => "PinPUp",
+            PlaySpeedDown => "PlaySpeedDown",
+            PlaySpeedReset => "PlaySpeedReset",
+            PlaySpeedUp => "PlaySpeedUp",
+            RandomToggle => "RandomToggle",
+            RcLowBattery => "RcLowBattery",
+            RecordSpeedNext => "RecordSpeedNext",
+            RfBypass => "RfBypass",
+            ScanChannelsToggle => "ScanChannelsToggle",
+            ScreenModeNext => "ScreenModeNext",
+            Settings => "Settings",
+            SplitScreenToggle => "SplitScreenToggle",
+            STBInput => "STBInput",
+            STBPower => "STBPower",
+            Subtitle => "Subtitle",
+            Teletext => "Teletext",
+            VideoModeNext => "VideoModeNext",
+            Wink => "Wink",
+            ZoomToggle => "ZoomToggle",
+            F1 => "F1",
+            F2 => "F2",
+            F3 => "F3",
+            F4 => "F4",
+            F5 => "F5",
+            F6 => "F6",
+            F7 => "F7",
+            F8 => "F8",
+            F9 => "F9",
+            F10 => "F10",
+            F11 => "F11",
+            F12 => "F12",
+            F13 => "F13",
+            F14 => "F14",
+            F15 => "F15",
+            F16 => "F16",
+            F17 => "F17",
+            F18 => "F18",
+            F19 => "F19",
+            F20 => "F20",
+            F21 => "F21",
+            F22 => "F22",
+            F23 => "F23",
+            F24 => "F24",
+            F25 => "F25",
+            F26 => "F26",
+            F27 => "F27",
+            F28 => "F28",
+            F29 => "F29",
+            F30 => "F30",
+            F31 => "F31",
+            F32 => "F32",
+            F33 => "F33",
+            F34 => "F34",
+            F35 => "F35",
 
         }
     }
+
+    /// All named keys, in the order they are declared.
+    pub const fn all() -> &'static [NamedKey] {
+        use self::NamedKey::*;
+        &[
+            Unidentified,
+            Alt,
+            AltGraph,
+            CapsLock,
+            Control,
+            Fn,
+            FnLock,
+            Meta,
+            NumLock,
+            ScrollLock,
+            Shift,
+            Symbol,
+            SymbolLock,
+            Hyper,
+            Super,
+            Enter,
+            Tab,
+            ArrowDown,
+            ArrowLeft,
+            ArrowRight,
+            ArrowUp,
+            End,
+            Home,
+            PageDown,
+            PageUp,
+            Backspace,
+            Clear,
+            Copy,
+            CrSel,
+            Cut,
+            Delete,
+            EraseEof,
+            ExSel,
+            Insert,
+            Paste,
+            Redo,
+            Undo,
+            Accept,
+            Again,
+            Attn,
+            Cancel,
+            ContextMenu,
+            Escape,
+            Execute,
+            Find,
+            Help,
+            Pause,
+            Play,
+            Props,
+            Select,
+            ZoomIn,
+            ZoomOut,
+            BrightnessDown,
+            BrightnessUp,
+            Eject,
+            LogOff,
+            Power,
+            PowerOff,
+            PrintScreen,
+            Hibernate,
+            Standby,
+            WakeUp,
+            AllCandidates,
+            Alphanumeric,
+            CodeInput,
+            Compose,
+            Convert,
+            Dead,
+            FinalMode,
+            GroupFirst,
+            GroupLast,
+            GroupNext,
+            GroupPrevious,
+            ModeChange,
+            NextCandidate,
+            NonConvert,
+            PreviousCandidate,
+            Process,
+            SingleCandidate,
+            HangulMode,
+            HanjaMode,
+            JunjaMode,
+            Eisu,
+            Hankaku,
+            Hiragana,
+            HiraganaKatakana,
+            KanaMode,
+            KanjiMode,
+            Katakana,
+            Romaji,
+            Zenkaku,
+            ZenkakuHankaku,
+            Soft1,
+            Soft2,
+            Soft3,
+            Soft4,
+            ChannelDown,
+            ChannelUp,
+            Close,
+            MailForward,
+            MailReply,
+            MailSend,
+            MediaClose,
+            MediaFastForward,
+            MediaPause,
+            MediaPlay,
+            MediaPlayPause,
+            MediaRecord,
+            MediaRewind,
+            MediaStop,
+            MediaTrackNext,
+            MediaTrackPrevious,
+            New,
+            Open,
+            Print,
+            Save,
+            SpellCheck,
+            Key11,
+            Key12,
+            AudioBalanceLeft,
+            AudioBalanceRight,
+            AudioBassBoostDown,
+            AudioBassBoostToggle,
+            AudioBassBoostUp,
+            AudioFaderFront,
+            AudioFaderRear,
+            AudioSurroundModeNext,
+            AudioTrebleDown,
+            AudioTrebleUp,
+            AudioVolumeDown,
+            AudioVolumeUp,
+            AudioVolumeMute,
+            MicrophoneToggle,
+            MicrophoneVolumeDown,
+            MicrophoneVolumeUp,
+            MicrophoneVolumeMute,
+            SpeechCorrectionList,
+            SpeechInputToggle,
+            LaunchApplication1,
+            LaunchApplication2,
+            LaunchCalendar,
+            LaunchContacts,
+            LaunchMail,
+            LaunchMediaPlayer,
+            LaunchMusicPlayer,
+            LaunchPhone,
+            LaunchScreenSaver,
+            LaunchSpreadsheet,
+            LaunchWebBrowser,
+            LaunchWebCam,
+            LaunchWordProcessor,
+            BrowserBack,
+            BrowserFavorites,
+            BrowserForward,
+            BrowserHome,
+            BrowserRefresh,
+            BrowserSearch,
+            BrowserStop,
+            AppSwitch,
+            Call,
+            Camera,
+            CameraFocus,
+            EndCall,
+            GoBack,
+            GoHome,
+            HeadsetHook,
+            LastNumberRedial,
+            Notification,
+            MannerMode,
+            VoiceDial,
+            TV,
+            TV3DMode,
+            TVAntennaCable,
+            TVAudioDescription,
+            TVAudioDescriptionMixDown,
+            TVAudioDescriptionMixUp,
+            TVContentsMenu,
+            TVDataService,
+            TVInput,
+            TVInputComponent1,
+            TVInputComponent2,
+            TVInputComposite1,
+            TVInputComposite2,
+            TVInputHDMI1,
+            TVInputHDMI2,
+            TVInputHDMI3,
+            TVInputHDMI4,
+            TVInputVGA1,
+            TVMediaContext,
+            TVNetwork,
+            TVNumberEntry,
+            TVPower,
+            TVRadioService,
+            TVSatellite,
+            TVSatelliteBS,
+            TVSatelliteCS,
+            TVSatelliteToggle,
+            TVTerrestrialAnalog,
+            TVTerrestrialDigital,
+            TVTimer,
+            AVRInput,
+            AVRPower,
+            ColorF0Red,
+            ColorF1Green,
+            ColorF2Yellow,
+            ColorF3Blue,
+            ColorF4Grey,
+            ColorF5Brown,
+            ClosedCaptionToggle,
+            Dimmer,
+            DisplaySwap,
+            DVR,
+            Exit,
+            FavoriteClear0,
+            FavoriteClear1,
+            FavoriteClear2,
+            FavoriteClear3,
+            FavoriteRecall0,
+            FavoriteRecall1,
+            FavoriteRecall2,
+            FavoriteRecall3,
+            FavoriteStore0,
+            FavoriteStore1,
+            FavoriteStore2,
+            FavoriteStore3,
+            Guide,
+            GuideNextDay,
+            GuidePreviousDay,
+            Info,
+            InstantReplay,
+            Link,
+            ListProgram,
+            LiveContent,
+            Lock,
+            MediaApps,
+            MediaAudioTrack,
+            MediaLast,
+            MediaSkipBackward,
+            MediaSkipForward,
+            MediaStepBackward,
+            MediaStepForward,
+            MediaTopMenu,
+            NavigateIn,
+            NavigateNext,
+            NavigateOut,
+            NavigatePrevious,
+            NextFavoriteChannel,
+            NextUserProfile,
+            OnDemand,
+            Pairing,
+            PinPDown,
+            PinPMove,
+            PinPToggle,
+            PinPUp,
+            PlaySpeedDown,
+            PlaySpeedReset,
+            PlaySpeedUp,
+            RandomToggle,
+            RcLowBattery,
+            RecordSpeedNext,
+            RfBypass,
+            ScanChannelsToggle,
+            ScreenModeNext,
+            Settings,
+            SplitScreenToggle,
+            STBInput,
+            STBPower,
+            Subtitle,
+            Teletext,
+            VideoModeNext,
+            Wink,
+            ZoomToggle,
+            F1,
+            F2,
+            F3,
+            F4,
+            F5,
+            F6,
+            F7,
+            F8,
+            F9,
+            F10,
+            F11,
+            F12,
+            F13,
+            F14,
+            F15,
+            F16,
+            F17,
+            F18,
+            F19,
+            F20,
+            F21,
+            F22,
+            F23,
+            F24,
+            F25,
+            F26,
+            F27,
+            F28,
+            F29,
+            F30,
+            F31,
+            F32,
+            F33,
+            F34,
+            F35,
+
+        ]
+    }
+}
+
+impl Display for NamedKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 impl FromStr for NamedKey {
@@ -1305,18 +1629,27 @@ impl FromStr for NamedKey {
             "F34" => Ok(F34),
             "F35" => Ok(F35),
 
-            _ => Err(UnrecognizedNamedKeyError),
+            _ => Err(UnrecognizedNamedKeyError { input: s.into() }),
         }
     }
 }
 
 /// Parse from string error, returned when string does not match to any [`NamedKey`] variant.
 #[derive(Clone, Debug)]
-pub struct UnrecognizedNamedKeyError;
+pub struct UnrecognizedNamedKeyError {
+    input: Box<str>,
+}
+
+impl UnrecognizedNamedKeyError {
+    /// The string that failed to parse.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
 
 impl fmt::Display for UnrecognizedNamedKeyError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Unrecognized key")
+        write!(f, "Unrecognized key: {:?}", self.input)
     }
 }
 
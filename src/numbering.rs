@@ -0,0 +1,694 @@
+//! Stable numeric identifiers for [`Code`] and [`NamedKey`].
+//!
+//! The numbers are part of the public API and never change: new variants
+//! are appended with the next free number and removed variants leave a gap.
+//! Codes are only deprecated, never removed, so they are numbered without
+//! gaps, see [`Code::index`]. The numbers are independent of the declaration
+//! order of the enums.
+
+#[cfg(not(feature = "std"))]
+use core::error::Error;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::{Code, NamedKey};
+
+/// Pairs of stable numbers and the corresponding [`Code`].
+#[allow(deprecated)]
+const CODE_NUMBERS: &[(u16, Code)] = &[
+    (0, Code::Backquote),
+    (1, Code::Backslash),
+    (2, Code::BracketLeft),
+    (3, Code::BracketRight),
+    (4, Code::Comma),
+    (5, Code::Digit0),
+    (6, Code::Digit1),
+    (7, Code::Digit2),
+    (8, Code::Digit3),
+    (9, Code::Digit4),
+    (10, Code::Digit5),
+    (11, Code::Digit6),
+    (12, Code::Digit7),
+    (13, Code::Digit8),
+    (14, Code::Digit9),
+    (15, Code::Equal),
+    (16, Code::IntlBackslash),
+    (17, Code::IntlRo),
+    (18, Code::IntlYen),
+    (19, Code::KeyA),
+    (20, Code::KeyB),
+    (21, Code::KeyC),
+    (22, Code::KeyD),
+    (23, Code::KeyE),
+    (24, Code::KeyF),
+    (25, Code::KeyG),
+    (26, Code::KeyH),
+    (27, Code::KeyI),
+    (28, Code::KeyJ),
+    (29, Code::KeyK),
+    (30, Code::KeyL),
+    (31, Code::KeyM),
+    (32, Code::KeyN),
+    (33, Code::KeyO),
+    (34, Code::KeyP),
+    (35, Code::KeyQ),
+    (36, Code::KeyR),
+    (37, Code::KeyS),
+    (38, Code::KeyT),
+    (39, Code::KeyU),
+    (40, Code::KeyV),
+    (41, Code::KeyW),
+    (42, Code::KeyX),
+    (43, Code::KeyY),
+    (44, Code::KeyZ),
+    (45, Code::Minus),
+    (46, Code::Period),
+    (47, Code::Quote),
+    (48, Code::Semicolon),
+    (49, Code::Slash),
+    (50, Code::AltLeft),
+    (51, Code::AltRight),
+    (52, Code::Backspace),
+    (53, Code::CapsLock),
+    (54, Code::ContextMenu),
+    (55, Code::ControlLeft),
+    (56, Code::ControlRight),
+    (57, Code::Enter),
+    (58, Code::MetaLeft),
+    (59, Code::MetaRight),
+    (60, Code::ShiftLeft),
+    (61, Code::ShiftRight),
+    (62, Code::Space),
+    (63, Code::Tab),
+    (64, Code::Convert),
+    (65, Code::KanaMode),
+    (66, Code::Lang1),
+    (67, Code::Lang2),
+    (68, Code::Lang3),
+    (69, Code::Lang4),
+    (70, Code::Lang5),
+    (71, Code::NonConvert),
+    (72, Code::Delete),
+    (73, Code::End),
+    (74, Code::Help),
+    (75, Code::Home),
+    (76, Code::Insert),
+    (77, Code::PageDown),
+    (78, Code::PageUp),
+    (79, Code::ArrowDown),
+    (80, Code::ArrowLeft),
+    (81, Code::ArrowRight),
+    (82, Code::ArrowUp),
+    (83, Code::NumLock),
+    (84, Code::Numpad0),
+    (85, Code::Numpad1),
+    (86, Code::Numpad2),
+    (87, Code::Numpad3),
+    (88, Code::Numpad4),
+    (89, Code::Numpad5),
+    (90, Code::Numpad6),
+    (91, Code::Numpad7),
+    (92, Code::Numpad8),
+    (93, Code::Numpad9),
+    (94, Code::NumpadAdd),
+    (95, Code::NumpadBackspace),
+    (96, Code::NumpadClear),
+    (97, Code::NumpadClearEntry),
+    (98, Code::NumpadComma),
+    (99, Code::NumpadDecimal),
+    (100, Code::NumpadDivide),
+    (101, Code::NumpadEnter),
+    (102, Code::NumpadEqual),
+    (103, Code::NumpadHash),
+    (104, Code::NumpadMemoryAdd),
+    (105, Code::NumpadMemoryClear),
+    (106, Code::NumpadMemoryRecall),
+    (107, Code::NumpadMemoryStore),
+    (108, Code::NumpadMemorySubtract),
+    (109, Code::NumpadMultiply),
+    (110, Code::NumpadParenLeft),
+    (111, Code::NumpadParenRight),
+    (112, Code::NumpadStar),
+    (113, Code::NumpadSubtract),
+    (114, Code::Escape),
+    (115, Code::Fn),
+    (116, Code::FnLock),
+    (117, Code::PrintScreen),
+    (118, Code::ScrollLock),
+    (119, Code::Pause),
+    (120, Code::BrowserBack),
+    (121, Code::BrowserFavorites),
+    (122, Code::BrowserForward),
+    (123, Code::BrowserHome),
+    (124, Code::BrowserRefresh),
+    (125, Code::BrowserSearch),
+    (126, Code::BrowserStop),
+    (127, Code::Eject),
+    (128, Code::LaunchApp1),
+    (129, Code::LaunchApp2),
+    (130, Code::LaunchMail),
+    (131, Code::MediaPlayPause),
+    (132, Code::MediaSelect),
+    (133, Code::MediaStop),
+    (134, Code::MediaTrackNext),
+    (135, Code::MediaTrackPrevious),
+    (136, Code::Power),
+    (137, Code::Sleep),
+    (138, Code::AudioVolumeDown),
+    (139, Code::AudioVolumeMute),
+    (140, Code::AudioVolumeUp),
+    (141, Code::WakeUp),
+    (142, Code::Hyper),
+    (143, Code::Super),
+    (144, Code::Turbo),
+    (145, Code::Abort),
+    (146, Code::Resume),
+    (147, Code::Suspend),
+    (148, Code::Again),
+    (149, Code::Copy),
+    (150, Code::Cut),
+    (151, Code::Find),
+    (152, Code::Open),
+    (153, Code::Paste),
+    (154, Code::Props),
+    (155, Code::Select),
+    (156, Code::Undo),
+    (157, Code::Hiragana),
+    (158, Code::Katakana),
+    (159, Code::Unidentified),
+    (160, Code::F1),
+    (161, Code::F2),
+    (162, Code::F3),
+    (163, Code::F4),
+    (164, Code::F5),
+    (165, Code::F6),
+    (166, Code::F7),
+    (167, Code::F8),
+    (168, Code::F9),
+    (169, Code::F10),
+    (170, Code::F11),
+    (171, Code::F12),
+    (172, Code::F13),
+    (173, Code::F14),
+    (174, Code::F15),
+    (175, Code::F16),
+    (176, Code::F17),
+    (177, Code::F18),
+    (178, Code::F19),
+    (179, Code::F20),
+    (180, Code::F21),
+    (181, Code::F22),
+    (182, Code::F23),
+    (183, Code::F24),
+    (184, Code::F25),
+    (185, Code::F26),
+    (186, Code::F27),
+    (187, Code::F28),
+    (188, Code::F29),
+    (189, Code::F30),
+    (190, Code::F31),
+    (191, Code::F32),
+    (192, Code::F33),
+    (193, Code::F34),
+    (194, Code::F35),
+    (195, Code::BrightnessDown),
+    (196, Code::BrightnessUp),
+    (197, Code::DisplayToggleIntExt),
+    (198, Code::KeyboardLayoutSelect),
+    (199, Code::LaunchAssistant),
+    (200, Code::LaunchControlPanel),
+    (201, Code::LaunchScreenSaver),
+    (202, Code::MailForward),
+    (203, Code::MailReply),
+    (204, Code::MailSend),
+    (205, Code::MediaFastForward),
+    (206, Code::MediaPause),
+    (207, Code::MediaPlay),
+    (208, Code::MediaRecord),
+    (209, Code::MediaRewind),
+    (210, Code::MicrophoneMuteToggle),
+    (211, Code::PrivacyScreenToggle),
+    (212, Code::KeyboardBacklightToggle),
+    (213, Code::SelectTask),
+    (214, Code::ShowAllWindows),
+    (215, Code::ZoomToggle),
+];
+
+/// Pairs of stable numbers and the corresponding [`NamedKey`].
+#[allow(deprecated)]
+const NAMED_KEY_NUMBERS: &[(u16, NamedKey)] = &[
+    (0, NamedKey::Unidentified),
+    (1, NamedKey::Alt),
+    (2, NamedKey::AltGraph),
+    (3, NamedKey::CapsLock),
+    (4, NamedKey::Control),
+    (5, NamedKey::Fn),
+    (6, NamedKey::FnLock),
+    (7, NamedKey::Meta),
+    (8, NamedKey::NumLock),
+    (9, NamedKey::ScrollLock),
+    (10, NamedKey::Shift),
+    (11, NamedKey::Symbol),
+    (12, NamedKey::SymbolLock),
+    (13, NamedKey::Hyper),
+    (14, NamedKey::Super),
+    (15, NamedKey::Enter),
+    (16, NamedKey::Tab),
+    (17, NamedKey::ArrowDown),
+    (18, NamedKey::ArrowLeft),
+    (19, NamedKey::ArrowRight),
+    (20, NamedKey::ArrowUp),
+    (21, NamedKey::End),
+    (22, NamedKey::Home),
+    (23, NamedKey::PageDown),
+    (24, NamedKey::PageUp),
+    (25, NamedKey::Backspace),
+    (26, NamedKey::Clear),
+    (27, NamedKey::Copy),
+    (28, NamedKey::CrSel),
+    (29, NamedKey::Cut),
+    (30, NamedKey::Delete),
+    (31, NamedKey::EraseEof),
+    (32, NamedKey::ExSel),
+    (33, NamedKey::Insert),
+    (34, NamedKey::Paste),
+    (35, NamedKey::Redo),
+    (36, NamedKey::Undo),
+    (37, NamedKey::Accept),
+    (38, NamedKey::Again),
+    (39, NamedKey::Attn),
+    (40, NamedKey::Cancel),
+    (41, NamedKey::ContextMenu),
+    (42, NamedKey::Escape),
+    (43, NamedKey::Execute),
+    (44, NamedKey::Find),
+    (45, NamedKey::Help),
+    (46, NamedKey::Pause),
+    (47, NamedKey::Play),
+    (48, NamedKey::Props),
+    (49, NamedKey::Select),
+    (50, NamedKey::ZoomIn),
+    (51, NamedKey::ZoomOut),
+    (52, NamedKey::BrightnessDown),
+    (53, NamedKey::BrightnessUp),
+    (54, NamedKey::Eject),
+    (55, NamedKey::LogOff),
+    (56, NamedKey::Power),
+    (57, NamedKey::PowerOff),
+    (58, NamedKey::PrintScreen),
+    (59, NamedKey::Hibernate),
+    (60, NamedKey::Standby),
+    (61, NamedKey::WakeUp),
+    (62, NamedKey::AllCandidates),
+    (63, NamedKey::Alphanumeric),
+    (64, NamedKey::CodeInput),
+    (65, NamedKey::Compose),
+    (66, NamedKey::Convert),
+    (67, NamedKey::Dead),
+    (68, NamedKey::FinalMode),
+    (69, NamedKey::GroupFirst),
+    (70, NamedKey::GroupLast),
+    (71, NamedKey::GroupNext),
+    (72, NamedKey::GroupPrevious),
+    (73, NamedKey::ModeChange),
+    (74, NamedKey::NextCandidate),
+    (75, NamedKey::NonConvert),
+    (76, NamedKey::PreviousCandidate),
+    (77, NamedKey::Process),
+    (78, NamedKey::SingleCandidate),
+    (79, NamedKey::HangulMode),
+    (80, NamedKey::HanjaMode),
+    (81, NamedKey::JunjaMode),
+    (82, NamedKey::Eisu),
+    (83, NamedKey::Hankaku),
+    (84, NamedKey::Hiragana),
+    (85, NamedKey::HiraganaKatakana),
+    (86, NamedKey::KanaMode),
+    (87, NamedKey::KanjiMode),
+    (88, NamedKey::Katakana),
+    (89, NamedKey::Romaji),
+    (90, NamedKey::Zenkaku),
+    (91, NamedKey::ZenkakuHankaku),
+    (92, NamedKey::Soft1),
+    (93, NamedKey::Soft2),
+    (94, NamedKey::Soft3),
+    (95, NamedKey::Soft4),
+    (96, NamedKey::ChannelDown),
+    (97, NamedKey::ChannelUp),
+    (98, NamedKey::Close),
+    (99, NamedKey::MailForward),
+    (100, NamedKey::MailReply),
+    (101, NamedKey::MailSend),
+    (102, NamedKey::MediaClose),
+    (103, NamedKey::MediaFastForward),
+    (104, NamedKey::MediaPause),
+    (105, NamedKey::MediaPlay),
+    (106, NamedKey::MediaPlayPause),
+    (107, NamedKey::MediaRecord),
+    (108, NamedKey::MediaRewind),
+    (109, NamedKey::MediaStop),
+    (110, NamedKey::MediaTrackNext),
+    (111, NamedKey::MediaTrackPrevious),
+    (112, NamedKey::New),
+    (113, NamedKey::Open),
+    (114, NamedKey::Print),
+    (115, NamedKey::Save),
+    (116, NamedKey::SpellCheck),
+    (117, NamedKey::Key11),
+    (118, NamedKey::Key12),
+    (119, NamedKey::AudioBalanceLeft),
+    (120, NamedKey::AudioBalanceRight),
+    (121, NamedKey::AudioBassBoostDown),
+    (122, NamedKey::AudioBassBoostToggle),
+    (123, NamedKey::AudioBassBoostUp),
+    (124, NamedKey::AudioFaderFront),
+    (125, NamedKey::AudioFaderRear),
+    (126, NamedKey::AudioSurroundModeNext),
+    (127, NamedKey::AudioTrebleDown),
+    (128, NamedKey::AudioTrebleUp),
+    (129, NamedKey::AudioVolumeDown),
+    (130, NamedKey::AudioVolumeUp),
+    (131, NamedKey::AudioVolumeMute),
+    (132, NamedKey::MicrophoneToggle),
+    (133, NamedKey::MicrophoneVolumeDown),
+    (134, NamedKey::MicrophoneVolumeUp),
+    (135, NamedKey::MicrophoneVolumeMute),
+    (136, NamedKey::SpeechCorrectionList),
+    (137, NamedKey::SpeechInputToggle),
+    (138, NamedKey::LaunchApplication1),
+    (139, NamedKey::LaunchApplication2),
+    (140, NamedKey::LaunchCalendar),
+    (141, NamedKey::LaunchContacts),
+    (142, NamedKey::LaunchMail),
+    (143, NamedKey::LaunchMediaPlayer),
+    (144, NamedKey::LaunchMusicPlayer),
+    (145, NamedKey::LaunchPhone),
+    (146, NamedKey::LaunchScreenSaver),
+    (147, NamedKey::LaunchSpreadsheet),
+    (148, NamedKey::LaunchWebBrowser),
+    (149, NamedKey::LaunchWebCam),
+    (150, NamedKey::LaunchWordProcessor),
+    (151, NamedKey::BrowserBack),
+    (152, NamedKey::BrowserFavorites),
+    (153, NamedKey::BrowserForward),
+    (154, NamedKey::BrowserHome),
+    (155, NamedKey::BrowserRefresh),
+    (156, NamedKey::BrowserSearch),
+    (157, NamedKey::BrowserStop),
+    (158, NamedKey::AppSwitch),
+    (159, NamedKey::Call),
+    (160, NamedKey::Camera),
+    (161, NamedKey::CameraFocus),
+    (162, NamedKey::EndCall),
+    (163, NamedKey::GoBack),
+    (164, NamedKey::GoHome),
+    (165, NamedKey::HeadsetHook),
+    (166, NamedKey::LastNumberRedial),
+    (167, NamedKey::Notification),
+    (168, NamedKey::MannerMode),
+    (169, NamedKey::VoiceDial),
+    (170, NamedKey::TV),
+    (171, NamedKey::TV3DMode),
+    (172, NamedKey::TVAntennaCable),
+    (173, NamedKey::TVAudioDescription),
+    (174, NamedKey::TVAudioDescriptionMixDown),
+    (175, NamedKey::TVAudioDescriptionMixUp),
+    (176, NamedKey::TVContentsMenu),
+    (177, NamedKey::TVDataService),
+    (178, NamedKey::TVInput),
+    (179, NamedKey::TVInputComponent1),
+    (180, NamedKey::TVInputComponent2),
+    (181, NamedKey::TVInputComposite1),
+    (182, NamedKey::TVInputComposite2),
+    (183, NamedKey::TVInputHDMI1),
+    (184, NamedKey::TVInputHDMI2),
+    (185, NamedKey::TVInputHDMI3),
+    (186, NamedKey::TVInputHDMI4),
+    (187, NamedKey::TVInputVGA1),
+    (188, NamedKey::TVMediaContext),
+    (189, NamedKey::TVNetwork),
+    (190, NamedKey::TVNumberEntry),
+    (191, NamedKey::TVPower),
+    (192, NamedKey::TVRadioService),
+    (193, NamedKey::TVSatellite),
+    (194, NamedKey::TVSatelliteBS),
+    (195, NamedKey::TVSatelliteCS),
+    (196, NamedKey::TVSatelliteToggle),
+    (197, NamedKey::TVTerrestrialAnalog),
+    (198, NamedKey::TVTerrestrialDigital),
+    (199, NamedKey::TVTimer),
+    (200, NamedKey::AVRInput),
+    (201, NamedKey::AVRPower),
+    (202, NamedKey::ColorF0Red),
+    (203, NamedKey::ColorF1Green),
+    (204, NamedKey::ColorF2Yellow),
+    (205, NamedKey::ColorF3Blue),
+    (206, NamedKey::ColorF4Grey),
+    (207, NamedKey::ColorF5Brown),
+    (208, NamedKey::ClosedCaptionToggle),
+    (209, NamedKey::Dimmer),
+    (210, NamedKey::DisplaySwap),
+    (211, NamedKey::DVR),
+    (212, NamedKey::Exit),
+    (213, NamedKey::FavoriteClear0),
+    (214, NamedKey::FavoriteClear1),
+    (215, NamedKey::FavoriteClear2),
+    (216, NamedKey::FavoriteClear3),
+    (217, NamedKey::FavoriteRecall0),
+    (218, NamedKey::FavoriteRecall1),
+    (219, NamedKey::FavoriteRecall2),
+    (220, NamedKey::FavoriteRecall3),
+    (221, NamedKey::FavoriteStore0),
+    (222, NamedKey::FavoriteStore1),
+    (223, NamedKey::FavoriteStore2),
+    (224, NamedKey::FavoriteStore3),
+    (225, NamedKey::Guide),
+    (226, NamedKey::GuideNextDay),
+    (227, NamedKey::GuidePreviousDay),
+    (228, NamedKey::Info),
+    (229, NamedKey::InstantReplay),
+    (230, NamedKey::Link),
+    (231, NamedKey::ListProgram),
+    (232, NamedKey::LiveContent),
+    (233, NamedKey::Lock),
+    (234, NamedKey::MediaApps),
+    (235, NamedKey::MediaAudioTrack),
+    (236, NamedKey::MediaLast),
+    (237, NamedKey::MediaSkipBackward),
+    (238, NamedKey::MediaSkipForward),
+    (239, NamedKey::MediaStepBackward),
+    (240, NamedKey::MediaStepForward),
+    (241, NamedKey::MediaTopMenu),
+    (242, NamedKey::NavigateIn),
+    (243, NamedKey::NavigateNext),
+    (244, NamedKey::NavigateOut),
+    (245, NamedKey::NavigatePrevious),
+    (246, NamedKey::NextFavoriteChannel),
+    (247, NamedKey::NextUserProfile),
+    (248, NamedKey::OnDemand),
+    (249, NamedKey::Pairing),
+    (250, NamedKey::PinPDown),
+    (251, NamedKey::PinPMove),
+    (252, NamedKey::PinPToggle),
+    (253, NamedKey::PinPUp),
+    (254, NamedKey::PlaySpeedDown),
+    (255, NamedKey::PlaySpeedReset),
+    (256, NamedKey::PlaySpeedUp),
+    (257, NamedKey::RandomToggle),
+    (258, NamedKey::RcLowBattery),
+    (259, NamedKey::RecordSpeedNext),
+    (260, NamedKey::RfBypass),
+    (261, NamedKey::ScanChannelsToggle),
+    (262, NamedKey::ScreenModeNext),
+    (263, NamedKey::Settings),
+    (264, NamedKey::SplitScreenToggle),
+    (265, NamedKey::STBInput),
+    (266, NamedKey::STBPower),
+    (267, NamedKey::Subtitle),
+    (268, NamedKey::Teletext),
+    (269, NamedKey::VideoModeNext),
+    (270, NamedKey::Wink),
+    (271, NamedKey::ZoomToggle),
+    (272, NamedKey::F1),
+    (273, NamedKey::F2),
+    (274, NamedKey::F3),
+    (275, NamedKey::F4),
+    (276, NamedKey::F5),
+    (277, NamedKey::F6),
+    (278, NamedKey::F7),
+    (279, NamedKey::F8),
+    (280, NamedKey::F9),
+    (281, NamedKey::F10),
+    (282, NamedKey::F11),
+    (283, NamedKey::F12),
+    (284, NamedKey::F13),
+    (285, NamedKey::F14),
+    (286, NamedKey::F15),
+    (287, NamedKey::F16),
+    (288, NamedKey::F17),
+    (289, NamedKey::F18),
+    (290, NamedKey::F19),
+    (291, NamedKey::F20),
+    (292, NamedKey::F21),
+    (293, NamedKey::F22),
+    (294, NamedKey::F23),
+    (295, NamedKey::F24),
+    (296, NamedKey::F25),
+    (297, NamedKey::F26),
+    (298, NamedKey::F27),
+    (299, NamedKey::F28),
+    (300, NamedKey::F29),
+    (301, NamedKey::F30),
+    (302, NamedKey::F31),
+    (303, NamedKey::F32),
+    (304, NamedKey::F33),
+    (305, NamedKey::F34),
+    (306, NamedKey::F35),
+];
+
+/// The stable number of each code, in the order the codes are declared.
+#[allow(deprecated)]
+const CODE_INDEX: [u16; Code::all().len()] = {
+    let mut numbers = [0; Code::all().len()];
+    let mut i = 0;
+    while i < CODE_NUMBERS.len() {
+        let (number, code) = CODE_NUMBERS[i];
+        numbers[code as usize] = number;
+        i += 1;
+    }
+    numbers
+};
+
+impl Code {
+    /// A dense index of the code, e.g. to build a lookup table in an array.
+    ///
+    /// The index is the stable number of the code (see [`u16::from`]). The
+    /// codes are numbered without gaps, so all codes have an index below
+    /// `Code::all().len()`. Like the number, the index never changes and
+    /// can be persisted.
+    pub const fn index(self) -> usize {
+        CODE_INDEX[self as usize] as usize
+    }
+
+    /// Get the code for an index returned by [`Code::index`].
+    pub fn from_index(index: usize) -> Option<Code> {
+        CODE_NUMBERS.get(index).map(|&(_, code)| code)
+    }
+}
+
+impl TryFrom<u16> for Code {
+    type Error = InvalidKeyNumber;
+
+    /// Get the code for its stable number.
+    fn try_from(number: u16) -> Result<Code, InvalidKeyNumber> {
+        Code::from_index(usize::from(number)).ok_or(InvalidKeyNumber(number))
+    }
+}
+
+impl From<Code> for u16 {
+    /// Get the stable number of a code.
+    fn from(code: Code) -> u16 {
+        CODE_INDEX[code as usize]
+    }
+}
+
+impl TryFrom<u16> for NamedKey {
+    type Error = InvalidKeyNumber;
+
+    /// Get the named key for its stable number.
+    fn try_from(number: u16) -> Result<NamedKey, InvalidKeyNumber> {
+        NAMED_KEY_NUMBERS
+            .iter()
+            .find(|&&(n, _)| n == number)
+            .map(|&(_, key)| key)
+            .ok_or(InvalidKeyNumber(number))
+    }
+}
+
+impl From<NamedKey> for u16 {
+    /// Get the stable number of a named key.
+    fn from(key: NamedKey) -> u16 {
+        NAMED_KEY_NUMBERS
+            .iter()
+            .find(|&&(_, k)| k == key)
+            .map(|&(n, _)| n)
+            .expect("every named key has a number")
+    }
+}
+
+/// Error returned when converting a `u16` that is not assigned to any
+/// [`Code`] or [`NamedKey`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvalidKeyNumber(u16);
+
+impl InvalidKeyNumber {
+    /// The number that is not assigned.
+    pub fn number(&self) -> u16 {
+        self.0
+    }
+}
+
+impl fmt::Display for InvalidKeyNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid key number: {}", self.0)
+    }
+}
+
+impl Error for InvalidKeyNumber {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn pinned_numbers() {
+        // These numbers are a stable contract, do not change them.
+        assert_eq!(u16::from(Code::Backquote), 0);
+        assert_eq!(u16::from(Code::KeyA), 19);
+        assert_eq!(u16::from(Code::Unidentified), 159);
+        assert_eq!(u16::from(NamedKey::Unidentified), 0);
+        assert_eq!(u16::from(NamedKey::Enter), 15);
+        assert_eq!(Code::try_from(19), Ok(Code::KeyA));
+        assert_eq!(NamedKey::try_from(0), Ok(NamedKey::Unidentified));
+        assert_eq!(Code::try_from(u16::MAX), Err(InvalidKeyNumber(u16::MAX)));
+    }
+
+    #[test]
+    fn all_numbered() {
+        for &code in Code::all() {
+            assert_eq!(Code::try_from(u16::from(code)), Ok(code));
+        }
+        for &key in NamedKey::all() {
+            assert_eq!(NamedKey::try_from(u16::from(key)), Ok(key));
+        }
+    }
+
+    #[test]
+    fn index() {
+        for &code in Code::all() {
+            assert_eq!(code.index(), usize::from(u16::from(code)));
+            assert_eq!(Code::from_index(code.index()), Some(code));
+        }
+        assert_eq!(Code::KeyA.index(), 19);
+        assert_eq!(Code::from_index(Code::all().len()), None);
+    }
+
+    #[test]
+    fn dense_code_numbers() {
+        assert_eq!(CODE_NUMBERS.len(), Code::all().len());
+        for (i, &(n, _)) in CODE_NUMBERS.iter().enumerate() {
+            assert_eq!(usize::from(n), i);
+        }
+    }
+
+    #[test]
+    fn unique_numbers() {
+        for (i, &(n, _)) in CODE_NUMBERS.iter().enumerate() {
+            assert!(CODE_NUMBERS[..i].iter().all(|&(m, _)| m != n));
+        }
+        for (i, &(n, _)) in NAMED_KEY_NUMBERS.iter().enumerate() {
+            assert!(NAMED_KEY_NUMBERS[..i].iter().all(|&(m, _)| m != n));
+        }
+    }
+}
@@ -0,0 +1,204 @@
+//! Conversions from PS/2 scan code set 1 (XT) scan codes.
+//!
+//! These are the scan codes read from the keyboard controller data port
+//! `0x60` on x86 with translation enabled.
+//!
+//! Specification: <https://www.win.tue.nl/~aeb/linux/kbd/scancodes-1.html>
+
+use crate::Code;
+
+/// Pairs of scan codes and the corresponding [`Code`].
+const PS2_SET1_CODES: &[(u8, Code)] = &[
+    (0x01, Code::Escape),
+    (0x02, Code::Digit1),
+    (0x03, Code::Digit2),
+    (0x04, Code::Digit3),
+    (0x05, Code::Digit4),
+    (0x06, Code::Digit5),
+    (0x07, Code::Digit6),
+    (0x08, Code::Digit7),
+    (0x09, Code::Digit8),
+    (0x0A, Code::Digit9),
+    (0x0B, Code::Digit0),
+    (0x0C, Code::Minus),
+    (0x0D, Code::Equal),
+    (0x0E, Code::Backspace),
+    (0x0F, Code::Tab),
+    (0x10, Code::KeyQ),
+    (0x11, Code::KeyW),
+    (0x12, Code::KeyE),
+    (0x13, Code::KeyR),
+    (0x14, Code::KeyT),
+    (0x15, Code::KeyY),
+    (0x16, Code::KeyU),
+    (0x17, Code::KeyI),
+    (0x18, Code::KeyO),
+    (0x19, Code::KeyP),
+    (0x1A, Code::BracketLeft),
+    (0x1B, Code::BracketRight),
+    (0x1C, Code::Enter),
+    (0x1D, Code::ControlLeft),
+    (0x1E, Code::KeyA),
+    (0x1F, Code::KeyS),
+    (0x20, Code::KeyD),
+    (0x21, Code::KeyF),
+    (0x22, Code::KeyG),
+    (0x23, Code::KeyH),
+    (0x24, Code::KeyJ),
+    (0x25, Code::KeyK),
+    (0x26, Code::KeyL),
+    (0x27, Code::Semicolon),
+    (0x28, Code::Quote),
+    (0x29, Code::Backquote),
+    (0x2A, Code::ShiftLeft),
+    (0x2B, Code::Backslash),
+    (0x2C, Code::KeyZ),
+    (0x2D, Code::KeyX),
+    (0x2E, Code::KeyC),
+    (0x2F, Code::KeyV),
+    (0x30, Code::KeyB),
+    (0x31, Code::KeyN),
+    (0x32, Code::KeyM),
+    (0x33, Code::Comma),
+    (0x34, Code::Period),
+    (0x35, Code::Slash),
+    (0x36, Code::ShiftRight),
+    (0x37, Code::NumpadMultiply),
+    (0x38, Code::AltLeft),
+    (0x39, Code::Space),
+    (0x3A, Code::CapsLock),
+    (0x3B, Code::F1),
+    (0x3C, Code::F2),
+    (0x3D, Code::F3),
+    (0x3E, Code::F4),
+    (0x3F, Code::F5),
+    (0x40, Code::F6),
+    (0x41, Code::F7),
+    (0x42, Code::F8),
+    (0x43, Code::F9),
+    (0x44, Code::F10),
+    (0x45, Code::NumLock),
+    (0x46, Code::ScrollLock),
+    (0x47, Code::Numpad7),
+    (0x48, Code::Numpad8),
+    (0x49, Code::Numpad9),
+    (0x4A, Code::NumpadSubtract),
+    (0x4B, Code::Numpad4),
+    (0x4C, Code::Numpad5),
+    (0x4D, Code::Numpad6),
+    (0x4E, Code::NumpadAdd),
+    (0x4F, Code::Numpad1),
+    (0x50, Code::Numpad2),
+    (0x51, Code::Numpad3),
+    (0x52, Code::Numpad0),
+    (0x53, Code::NumpadDecimal),
+    (0x56, Code::IntlBackslash),
+    (0x57, Code::F11),
+    (0x58, Code::F12),
+    (0x70, Code::KanaMode),
+    (0x73, Code::IntlRo),
+    (0x79, Code::Convert),
+    (0x7B, Code::NonConvert),
+    (0x7D, Code::IntlYen),
+    (0x7E, Code::NumpadComma),
+];
+
+/// Pairs of scan codes following the `0xE0` prefix and the corresponding [`Code`].
+const PS2_SET1_EXTENDED_CODES: &[(u8, Code)] = &[
+    (0x10, Code::MediaTrackPrevious),
+    (0x19, Code::MediaTrackNext),
+    (0x1C, Code::NumpadEnter),
+    (0x1D, Code::ControlRight),
+    (0x20, Code::AudioVolumeMute),
+    (0x21, Code::LaunchApp2),
+    (0x22, Code::MediaPlayPause),
+    (0x24, Code::MediaStop),
+    (0x2E, Code::AudioVolumeDown),
+    (0x30, Code::AudioVolumeUp),
+    (0x32, Code::BrowserHome),
+    (0x35, Code::NumpadDivide),
+    (0x37, Code::PrintScreen),
+    (0x38, Code::AltRight),
+    (0x47, Code::Home),
+    (0x48, Code::ArrowUp),
+    (0x49, Code::PageUp),
+    (0x4B, Code::ArrowLeft),
+    (0x4D, Code::ArrowRight),
+    (0x4F, Code::End),
+    (0x50, Code::ArrowDown),
+    (0x51, Code::PageDown),
+    (0x52, Code::Insert),
+    (0x53, Code::Delete),
+    (0x5B, Code::MetaLeft),
+    (0x5C, Code::MetaRight),
+    (0x5D, Code::ContextMenu),
+    (0x5E, Code::Power),
+    (0x5F, Code::Sleep),
+    (0x63, Code::WakeUp),
+    (0x65, Code::BrowserSearch),
+    (0x66, Code::BrowserFavorites),
+    (0x67, Code::BrowserRefresh),
+    (0x68, Code::BrowserStop),
+    (0x69, Code::BrowserForward),
+    (0x6A, Code::BrowserBack),
+    (0x6B, Code::LaunchApp1),
+    (0x6C, Code::LaunchMail),
+    (0x6D, Code::MediaSelect),
+];
+
+/// The bit that is set in break (key release) scan codes.
+const BREAK_BIT: u8 = 0x80;
+
+impl Code {
+    /// Get the code for a PS/2 scan code set 1 scan code.
+    ///
+    /// Both make (key press) and break (key release) codes are accepted,
+    /// a scan code is a break code if its high bit `0x80` is set.
+    /// For scan codes that follow the `0xE0` prefix use
+    /// [`Code::from_ps2_set1_extended`].
+    ///
+    /// Returns `None` if the scan code has no corresponding [`Code`].
+    pub fn from_ps2_set1(scancode: u8) -> Option<Code> {
+        find(PS2_SET1_CODES, scancode & !BREAK_BIT)
+    }
+
+    /// Get the code for a PS/2 scan code set 1 scan code that follows the
+    /// `0xE0` prefix.
+    ///
+    /// Like [`Code::from_ps2_set1`] both make and break codes are accepted.
+    /// The fake shift codes `0x2A` and `0x36` sent with some extended keys
+    /// return `None`.
+    pub fn from_ps2_set1_extended(scancode: u8) -> Option<Code> {
+        find(PS2_SET1_EXTENDED_CODES, scancode & !BREAK_BIT)
+    }
+}
+
+fn find(table: &[(u8, Code)], scancode: u8) -> Option<Code> {
+    table
+        .iter()
+        .find(|&&(s, _)| s == scancode)
+        .map(|&(_, code)| code)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ps2_set1() {
+        assert_eq!(Code::from_ps2_set1(0x1E), Some(Code::KeyA));
+        assert_eq!(Code::from_ps2_set1(0x9E), Some(Code::KeyA));
+        assert_eq!(Code::from_ps2_set1(0x00), None);
+        assert_eq!(Code::from_ps2_set1_extended(0x48), Some(Code::ArrowUp));
+        assert_eq!(Code::from_ps2_set1_extended(0xC8), Some(Code::ArrowUp));
+        assert_eq!(Code::from_ps2_set1(0x48), Some(Code::Numpad8));
+        assert_eq!(Code::from_ps2_set1_extended(0x2A), None);
+    }
+
+    #[test]
+    fn no_break_bit() {
+        for &(scancode, _) in PS2_SET1_CODES.iter().chain(PS2_SET1_EXTENDED_CODES) {
+            assert_eq!(scancode & BREAK_BIT, 0);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use alloc::vec::Vec;
+
+use crate::{Code, KeyboardEvent};
+
+/// Ignore auto-repeated events for some keys.
+///
+/// Games often want a key like jump to trigger only once when held, while
+/// movement keys should keep repeating.
+///
+/// ```rust
+/// # use keyboard_types::{Code, KeyboardEvent, NamedKey, RepeatFilter};
+/// let mut filter = RepeatFilter::new();
+/// filter.suppress(Code::Space);
+/// let jump = KeyboardEvent {
+///     repeat: true,
+///     ..KeyboardEvent::key_down(NamedKey::Unidentified, Code::Space)
+/// };
+/// assert!(!filter.accept(&jump));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct RepeatFilter {
+    codes: Vec<Code>,
+}
+
+impl RepeatFilter {
+    /// Create a filter that accepts all events.
+    pub fn new() -> RepeatFilter {
+        RepeatFilter { codes: Vec::new() }
+    }
+
+    /// Suppress auto-repeated events for a key.
+    pub fn suppress(&mut self, code: Code) {
+        if !self.codes.contains(&code) {
+            self.codes.push(code);
+        }
+    }
+
+    /// Return `false` if the event is an auto-repeat of a suppressed key.
+    pub fn accept(&self, event: &KeyboardEvent) -> bool {
+        !(event.repeat && self.codes.contains(&event.code))
+    }
+}
+
+impl FromIterator<Code> for RepeatFilter {
+    fn from_iter<I: IntoIterator<Item = Code>>(iter: I) -> RepeatFilter {
+        let mut filter = RepeatFilter::new();
+        for code in iter {
+            filter.suppress(code);
+        }
+        filter
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::NamedKey;
+
+    #[test]
+    fn accept() {
+        let filter: RepeatFilter = [Code::Space].into_iter().collect();
+        let jump = KeyboardEvent::key_down(NamedKey::Unidentified, Code::Space);
+        assert!(filter.accept(&jump));
+        let repeat = KeyboardEvent {
+            repeat: true,
+            ..jump
+        };
+        assert!(!filter.accept(&repeat));
+        let walk = KeyboardEvent {
+            repeat: true,
+            ..KeyboardEvent::key_down(NamedKey::ArrowLeft, Code::ArrowLeft)
+        };
+        assert!(filter.accept(&walk));
+    }
+}
@@ -0,0 +1,253 @@
+//! Conversions from and to SDL2 scancodes and keycodes.
+//!
+//! SDL scancodes below 256 are the USB HID usages of the Keyboard/Keypad
+//! usage page, see [`Code::from_hid_keyboard_usage`].
+//!
+//! Specification: <https://github.com/libsdl-org/SDL/blob/SDL2/include/SDL_scancode.h>
+
+use crate::{Code, Key, NamedKey};
+
+/// Pairs of SDL scancodes above the HID usage range and the corresponding [`Code`].
+const SDL_CODES: &[(i32, Code)] = &[
+    (258, Code::MediaTrackNext),          // SDL_SCANCODE_AUDIONEXT
+    (259, Code::MediaTrackPrevious),      // SDL_SCANCODE_AUDIOPREV
+    (260, Code::MediaStop),               // SDL_SCANCODE_AUDIOSTOP
+    (261, Code::MediaPlayPause),          // SDL_SCANCODE_AUDIOPLAY
+    (262, Code::AudioVolumeMute),         // SDL_SCANCODE_AUDIOMUTE
+    (263, Code::MediaSelect),             // SDL_SCANCODE_MEDIASELECT
+    (265, Code::LaunchMail),              // SDL_SCANCODE_MAIL
+    (268, Code::BrowserSearch),           // SDL_SCANCODE_AC_SEARCH
+    (269, Code::BrowserHome),             // SDL_SCANCODE_AC_HOME
+    (270, Code::BrowserBack),             // SDL_SCANCODE_AC_BACK
+    (271, Code::BrowserForward),          // SDL_SCANCODE_AC_FORWARD
+    (272, Code::BrowserStop),             // SDL_SCANCODE_AC_STOP
+    (273, Code::BrowserRefresh),          // SDL_SCANCODE_AC_REFRESH
+    (274, Code::BrowserFavorites),        // SDL_SCANCODE_AC_BOOKMARKS
+    (275, Code::BrightnessDown),          // SDL_SCANCODE_BRIGHTNESSDOWN
+    (276, Code::BrightnessUp),            // SDL_SCANCODE_BRIGHTNESSUP
+    (277, Code::DisplayToggleIntExt),     // SDL_SCANCODE_DISPLAYSWITCH
+    (278, Code::KeyboardBacklightToggle), // SDL_SCANCODE_KBDILLUMTOGGLE
+    (281, Code::Eject),                   // SDL_SCANCODE_EJECT
+    (282, Code::Sleep),                   // SDL_SCANCODE_SLEEP
+    (283, Code::LaunchApp1),              // SDL_SCANCODE_APP1
+    (284, Code::LaunchApp2),              // SDL_SCANCODE_APP2
+    (285, Code::MediaRewind),             // SDL_SCANCODE_AUDIOREWIND
+    (286, Code::MediaFastForward),        // SDL_SCANCODE_AUDIOFASTFORWARD
+];
+
+/// Pairs of SDL scancodes and the corresponding [`NamedKey`], for keycodes
+/// that are created from a scancode (`SDL_SCANCODE_TO_KEYCODE`).
+const SDL_NAMED_KEYS: &[(i32, NamedKey)] = &[
+    (57, NamedKey::CapsLock),            // SDLK_CAPSLOCK
+    (58, NamedKey::F1),                  // SDLK_F1
+    (59, NamedKey::F2),                  // SDLK_F2
+    (60, NamedKey::F3),                  // SDLK_F3
+    (61, NamedKey::F4),                  // SDLK_F4
+    (62, NamedKey::F5),                  // SDLK_F5
+    (63, NamedKey::F6),                  // SDLK_F6
+    (64, NamedKey::F7),                  // SDLK_F7
+    (65, NamedKey::F8),                  // SDLK_F8
+    (66, NamedKey::F9),                  // SDLK_F9
+    (67, NamedKey::F10),                 // SDLK_F10
+    (68, NamedKey::F11),                 // SDLK_F11
+    (69, NamedKey::F12),                 // SDLK_F12
+    (70, NamedKey::PrintScreen),         // SDLK_PRINTSCREEN
+    (71, NamedKey::ScrollLock),          // SDLK_SCROLLLOCK
+    (72, NamedKey::Pause),               // SDLK_PAUSE
+    (73, NamedKey::Insert),              // SDLK_INSERT
+    (74, NamedKey::Home),                // SDLK_HOME
+    (75, NamedKey::PageUp),              // SDLK_PAGEUP
+    (77, NamedKey::End),                 // SDLK_END
+    (78, NamedKey::PageDown),            // SDLK_PAGEDOWN
+    (79, NamedKey::ArrowRight),          // SDLK_RIGHT
+    (80, NamedKey::ArrowLeft),           // SDLK_LEFT
+    (81, NamedKey::ArrowDown),           // SDLK_DOWN
+    (82, NamedKey::ArrowUp),             // SDLK_UP
+    (83, NamedKey::NumLock),             // SDLK_NUMLOCKCLEAR
+    (88, NamedKey::Enter),               // SDLK_KP_ENTER
+    (101, NamedKey::ContextMenu),        // SDLK_APPLICATION
+    (102, NamedKey::Power),              // SDLK_POWER
+    (104, NamedKey::F13),                // SDLK_F13
+    (105, NamedKey::F14),                // SDLK_F14
+    (106, NamedKey::F15),                // SDLK_F15
+    (107, NamedKey::F16),                // SDLK_F16
+    (108, NamedKey::F17),                // SDLK_F17
+    (109, NamedKey::F18),                // SDLK_F18
+    (110, NamedKey::F19),                // SDLK_F19
+    (111, NamedKey::F20),                // SDLK_F20
+    (112, NamedKey::F21),                // SDLK_F21
+    (113, NamedKey::F22),                // SDLK_F22
+    (114, NamedKey::F23),                // SDLK_F23
+    (115, NamedKey::F24),                // SDLK_F24
+    (116, NamedKey::Execute),            // SDLK_EXECUTE
+    (117, NamedKey::Help),               // SDLK_HELP
+    (119, NamedKey::Select),             // SDLK_SELECT
+    (120, NamedKey::MediaStop),          // SDLK_STOP
+    (121, NamedKey::Again),              // SDLK_AGAIN
+    (122, NamedKey::Undo),               // SDLK_UNDO
+    (123, NamedKey::Cut),                // SDLK_CUT
+    (124, NamedKey::Copy),               // SDLK_COPY
+    (125, NamedKey::Paste),              // SDLK_PASTE
+    (126, NamedKey::Find),               // SDLK_FIND
+    (127, NamedKey::AudioVolumeMute),    // SDLK_MUTE
+    (128, NamedKey::AudioVolumeUp),      // SDLK_VOLUMEUP
+    (129, NamedKey::AudioVolumeDown),    // SDLK_VOLUMEDOWN
+    (154, NamedKey::Cancel),             // SDLK_CANCEL
+    (156, NamedKey::Clear),              // SDLK_CLEAR
+    (224, NamedKey::Control),            // SDLK_LCTRL
+    (225, NamedKey::Shift),              // SDLK_LSHIFT
+    (226, NamedKey::Alt),                // SDLK_LALT
+    (227, NamedKey::Meta),               // SDLK_LGUI
+    (228, NamedKey::Control),            // SDLK_RCTRL
+    (229, NamedKey::Shift),              // SDLK_RSHIFT
+    (230, NamedKey::Alt),                // SDLK_RALT
+    (231, NamedKey::Meta),               // SDLK_RGUI
+    (257, NamedKey::ModeChange),         // SDLK_MODE
+    (258, NamedKey::MediaTrackNext),     // SDLK_AUDIONEXT
+    (259, NamedKey::MediaTrackPrevious), // SDLK_AUDIOPREV
+    (260, NamedKey::MediaStop),          // SDLK_AUDIOSTOP
+    (261, NamedKey::MediaPlayPause),     // SDLK_AUDIOPLAY
+    (262, NamedKey::AudioVolumeMute),    // SDLK_AUDIOMUTE
+    (264, NamedKey::LaunchWebBrowser),   // SDLK_WWW
+    (265, NamedKey::LaunchMail),         // SDLK_MAIL
+    (268, NamedKey::BrowserSearch),      // SDLK_AC_SEARCH
+    (269, NamedKey::BrowserHome),        // SDLK_AC_HOME
+    (270, NamedKey::BrowserBack),        // SDLK_AC_BACK
+    (271, NamedKey::BrowserForward),     // SDLK_AC_FORWARD
+    (272, NamedKey::BrowserStop),        // SDLK_AC_STOP
+    (273, NamedKey::BrowserRefresh),     // SDLK_AC_REFRESH
+    (274, NamedKey::BrowserFavorites),   // SDLK_AC_BOOKMARKS
+    (275, NamedKey::BrightnessDown),     // SDLK_BRIGHTNESSDOWN
+    (276, NamedKey::BrightnessUp),       // SDLK_BRIGHTNESSUP
+    (281, NamedKey::Eject),              // SDLK_EJECT
+    (282, NamedKey::Standby),            // SDLK_SLEEP
+    (283, NamedKey::LaunchApplication1), // SDLK_APP1
+    (284, NamedKey::LaunchApplication2), // SDLK_APP2
+    (285, NamedKey::MediaRewind),        // SDLK_AUDIOREWIND
+    (286, NamedKey::MediaFastForward),   // SDLK_AUDIOFASTFORWARD
+];
+
+/// Bit set in SDL keycodes that are created from a scancode.
+const SCANCODE_MASK: i32 = 1 << 30;
+
+/// The highest usage on the Keyboard/Keypad usage page with a [`Code`].
+const MAX_HID_USAGE: i32 = 0xE7;
+
+impl Code {
+    /// Get the code for an SDL scancode (`SDL_SCANCODE_*`).
+    ///
+    /// Returns `None` if the scancode has no corresponding [`Code`].
+    pub fn from_sdl_scancode(scancode: i32) -> Option<Code> {
+        if (0..=MAX_HID_USAGE).contains(&scancode) {
+            Code::from_hid_keyboard_usage(scancode as u16)
+        } else {
+            SDL_CODES
+                .iter()
+                .find(|&&(s, _)| s == scancode)
+                .map(|&(_, code)| code)
+        }
+    }
+
+    /// Get the SDL scancode (`SDL_SCANCODE_*`) for a code.
+    ///
+    /// Returns `None` if the code has no corresponding scancode.
+    pub fn to_sdl_scancode(self) -> Option<i32> {
+        (0..=MAX_HID_USAGE)
+            .find(|&usage| Code::from_hid_keyboard_usage(usage as u16) == Some(self))
+            .or_else(|| {
+                SDL_CODES
+                    .iter()
+                    .find(|&&(_, code)| code == self)
+                    .map(|&(s, _)| s)
+            })
+    }
+}
+
+impl NamedKey {
+    /// Get the named key for an SDL keycode (`SDLK_*`).
+    ///
+    /// Returns `None` for keycodes of characters and for keycodes without
+    /// a corresponding [`NamedKey`].
+    pub fn from_sdl_keycode(keycode: i32) -> Option<NamedKey> {
+        match keycode {
+            0x08 => Some(NamedKey::Backspace),
+            0x09 => Some(NamedKey::Tab),
+            0x0D => Some(NamedKey::Enter),
+            0x1B => Some(NamedKey::Escape),
+            0x7F => Some(NamedKey::Delete),
+            _ if keycode & SCANCODE_MASK != 0 => {
+                let scancode = keycode & !SCANCODE_MASK;
+                SDL_NAMED_KEYS
+                    .iter()
+                    .find(|&&(s, _)| s == scancode)
+                    .map(|&(_, key)| key)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Key {
+    /// Get the key for an SDL keycode (`SDLK_*`).
+    ///
+    /// Keycodes of printable characters become a [`Key::Character`], other
+    /// keycodes are converted with [`NamedKey::from_sdl_keycode`]. Returns
+    /// `None` if the keycode is neither.
+    ///
+    /// SDL keycodes do not take modifiers into account, so letters are
+    /// always lowercase. Use the text input events of SDL for text.
+    pub fn from_sdl_keycode(keycode: i32) -> Option<Key> {
+        if let Some(key) = NamedKey::from_sdl_keycode(keycode) {
+            return Some(Key::Named(key));
+        }
+        u32::try_from(keycode)
+            .ok()
+            .and_then(char::from_u32)
+            .filter(|c| !c.is_control())
+            .map(Key::from)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn sdl_scancode() {
+        assert_eq!(Code::from_sdl_scancode(4), Some(Code::KeyA));
+        assert_eq!(Code::from_sdl_scancode(258), Some(Code::MediaTrackNext));
+        assert_eq!(Code::from_sdl_scancode(0), None);
+        assert_eq!(Code::from_sdl_scancode(-1), None);
+        assert_eq!(Code::KeyA.to_sdl_scancode(), Some(4));
+        assert_eq!(Code::Backslash.to_sdl_scancode(), Some(0x31));
+        assert_eq!(Code::Unidentified.to_sdl_scancode(), None);
+    }
+
+    #[test]
+    fn round_trip() {
+        for &code in Code::all() {
+            if let Some(scancode) = code.to_sdl_scancode() {
+                assert_eq!(Code::from_sdl_scancode(scancode), Some(code));
+            }
+        }
+    }
+
+    #[test]
+    fn sdl_keycode() {
+        assert_eq!(
+            Key::from_sdl_keycode('a' as i32),
+            Some(Key::Character("a".to_string()))
+        );
+        assert_eq!(
+            Key::from_sdl_keycode(0x0D),
+            Some(Key::Named(NamedKey::Enter))
+        );
+        assert_eq!(
+            Key::from_sdl_keycode(82 | SCANCODE_MASK),
+            Some(Key::Named(NamedKey::ArrowUp))
+        );
+        assert_eq!(NamedKey::from_sdl_keycode('a' as i32), None);
+        assert_eq!(Key::from_sdl_keycode(0), None);
+    }
+}
@@ -1,4 +1,16 @@
-use crate::{Key, KeyState, KeyboardEvent, Modifiers};
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use core::error::Error;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::{
+    Code, Key, KeyState, KeyboardEvent, KeyboardState, Modifiers, NamedKey, UnrecognizedKeyError,
+    UnrecognizedModifierError,
+};
 
 /// Match keyboard shortcuts and execute actions.
 ///
@@ -11,7 +23,8 @@ use crate::{Key, KeyState, KeyboardEvent, Modifiers};
 /// act on the shortcut. This is also true for the release of the
 /// C key as else only key release events would be forwarded.
 ///
-/// ASCII letters are compared ignoring case. Only takes
+/// A key value of a single ASCII letter is compared ignoring case, all
+/// other key values must be equal. Only takes
 /// the shift, control, alt and meta modifiers into account.
 /// If other modifiers beside those expected are found
 /// the shortcut is not matched.
@@ -25,12 +38,11 @@ pub struct ShortcutMatcher<T> {
 
 impl<T> ShortcutMatcher<T> {
     /// Create a new shortcut matcher.
-    pub fn new(state: KeyState, key: Key, mut modifiers: Modifiers) -> ShortcutMatcher<T> {
-        modifiers &= Modifiers::SHIFT | Modifiers::CONTROL | Modifiers::ALT | Modifiers::META;
+    pub fn new(state: KeyState, key: Key, modifiers: Modifiers) -> ShortcutMatcher<T> {
         ShortcutMatcher {
             state,
             key,
-            modifiers,
+            modifiers: modifiers.shortcut_only(),
             matched: false,
             value: None,
         }
@@ -60,6 +72,7 @@ impl<T> ShortcutMatcher<T> {
     /// #     modifiers: Modifiers::empty(),
     /// #     repeat: false,
     /// #     is_composing: false,
+    /// #     timestamp: None,
     /// # };
     /// // Create a matcher from a keyboard event.
     /// // Shortcuts are tested in-order.
@@ -93,6 +106,60 @@ impl<T> ShortcutMatcher<T> {
         self
     }
 
+    /// Test a keyboard shortcut with a named key.
+    ///
+    /// Same as `shortcut` with the key wrapped in [`Key::Named`].
+    ///
+    /// ```rust
+    /// # use keyboard_types::{Code, KeyboardEvent, Modifiers, NamedKey, ShortcutMatcher};
+    /// # fn refresh() {}
+    /// # let event = KeyboardEvent::key_down(NamedKey::F5, Code::F5);
+    /// ShortcutMatcher::from_event(event)
+    /// .shortcut_named(Modifiers::empty(), NamedKey::F5, refresh);
+    /// ```
+    pub fn shortcut_named<F>(self, modifiers: Modifiers, key: NamedKey, f: F) -> ShortcutMatcher<T>
+    where
+        F: (FnOnce() -> T),
+    {
+        self.shortcut(modifiers, Key::Named(key), f)
+    }
+
+    /// Test a keyboard shortcut with a modifier pattern.
+    ///
+    /// Like `shortcut`, but the modifiers only need to match the
+    /// [`ModifierPattern`] instead of being equal.
+    ///
+    /// ```rust
+    /// # use keyboard_types::{Code, KeyboardEvent, ModifierPattern, Modifiers, NamedKey, ShortcutMatcher};
+    /// # fn close() {}
+    /// # let event = KeyboardEvent::key_down(NamedKey::F4, Code::F4);
+    /// // Control must be down, Alt must be up and Shift does not matter.
+    /// let pattern = ModifierPattern::new(Modifiers::CONTROL, Modifiers::ALT);
+    /// ShortcutMatcher::from_event(event)
+    /// .shortcut_pattern(pattern, 'w', close);
+    /// ```
+    pub fn shortcut_pattern<K, F>(
+        mut self,
+        pattern: ModifierPattern,
+        key: K,
+        f: F,
+    ) -> ShortcutMatcher<T>
+    where
+        K: MatchKey,
+        F: (FnOnce() -> T),
+    {
+        if self.matched {
+            return self;
+        }
+        if pattern.matches(self.modifiers) && key.match_key(&self.key) {
+            if self.state.is_down() {
+                self.value = Some(f());
+            }
+            self.matched = true;
+        }
+        self
+    }
+
     /// Only test a shortcut if the enabled flag is set.
     ///
     /// If the `enabled` flag is true behaves the same as
@@ -112,6 +179,7 @@ impl<T> ShortcutMatcher<T> {
     /// #     modifiers: Modifiers::empty(),
     /// #     repeat: false,
     /// #     is_composing: false,
+    /// #     timestamp: None,
     /// # };
     /// ShortcutMatcher::from_event(event)
     /// .shortcut(Modifiers::CONTROL, 'c', copy)
@@ -156,13 +224,361 @@ impl<T> ShortcutMatcher<T> {
     }
 }
 
+/// Modifiers that must be pressed and modifiers that must not be pressed.
+///
+/// Modifiers in neither set may be pressed or not.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ModifierPattern {
+    /// Modifiers that must be pressed.
+    pub required: Modifiers,
+    /// Modifiers that must not be pressed.
+    pub forbidden: Modifiers,
+}
+
+impl ModifierPattern {
+    /// Create a pattern from the required and forbidden modifiers.
+    pub fn new(required: Modifiers, forbidden: Modifiers) -> ModifierPattern {
+        ModifierPattern {
+            required,
+            forbidden,
+        }
+    }
+
+    /// A pattern that matches exactly the given modifiers.
+    ///
+    /// This is how [`ShortcutMatcher::shortcut`] compares the modifiers.
+    pub fn exact(modifiers: Modifiers) -> ModifierPattern {
+        ModifierPattern::new(modifiers, Modifiers::all().shortcut_only() - modifiers)
+    }
+
+    /// Return `true` if all required and none of the forbidden modifiers are pressed.
+    pub fn matches(&self, modifiers: Modifiers) -> bool {
+        modifiers.is_superset_of(self.required) && modifiers.is_disjoint(self.forbidden)
+    }
+}
+
+/// A table of keyboard shortcuts bound to actions.
+///
+/// Like [`ShortcutMatcher`] only the shift, control, alt and meta modifiers
+/// are taken into account and single ASCII letters are compared ignoring case.
+/// If a chord is bound more than once the first binding is used, see
+/// [`ShortcutTable::conflicts`] to detect this.
+#[derive(Clone, Debug)]
+pub struct ShortcutTable<T> {
+    bindings: Vec<(Modifiers, Key, T)>,
+}
+
+impl<T> ShortcutTable<T> {
+    /// Create an empty table.
+    pub fn new() -> ShortcutTable<T> {
+        ShortcutTable {
+            bindings: Vec::new(),
+        }
+    }
+
+    /// Bind an action to a shortcut.
+    pub fn insert(&mut self, modifiers: Modifiers, key: Key, action: T) {
+        self.bindings.push((modifiers.shortcut_only(), key, action));
+    }
+
+    /// Get the action bound to a shortcut.
+    pub fn get(&self, modifiers: Modifiers, key: &Key) -> Option<&T> {
+        let modifiers = modifiers.shortcut_only();
+        self.bindings
+            .iter()
+            .find(|(m, k, _)| *m == modifiers && same_key(k, key))
+            .map(|(_, _, action)| action)
+    }
+
+    /// Return the shortcuts that are bound more than once.
+    ///
+    /// Every conflicting shortcut is returned once, in the order
+    /// of their first binding.
+    ///
+    /// ```rust
+    /// # use keyboard_types::{Key, Modifiers, ShortcutTable};
+    /// let mut table = ShortcutTable::new();
+    /// table.insert(Modifiers::CONTROL, Key::Character("s".to_owned()), "save");
+    /// table.insert(Modifiers::CONTROL, Key::Character("S".to_owned()), "save all");
+    /// assert_eq!(
+    ///     table.conflicts(),
+    ///     [(Modifiers::CONTROL, Key::Character("s".to_owned()))]
+    /// );
+    /// ```
+    pub fn conflicts(&self) -> Vec<(Modifiers, Key)> {
+        let mut conflicts: Vec<(Modifiers, Key)> = Vec::new();
+        for (i, (modifiers, key, _)) in self.bindings.iter().enumerate() {
+            let known = conflicts
+                .iter()
+                .any(|(m, k)| m == modifiers && same_key(k, key));
+            let bound_again = self.bindings[i + 1..]
+                .iter()
+                .any(|(m, k, _)| m == modifiers && same_key(k, key));
+            if !known && bound_again {
+                conflicts.push((*modifiers, key.clone()));
+            }
+        }
+        conflicts
+    }
+}
+
+impl<T> Default for ShortcutTable<T> {
+    fn default() -> Self {
+        ShortcutTable::new()
+    }
+}
+
+/// A set of chords of keys that are pressed at the same time, like
+/// <kbd>J</kbd>+<kbd>K</kbd>, bound to actions.
+///
+/// Unlike a [`ShortcutTable`] a chord consists of any number of non-modifier
+/// keys, identified by their [`Code`]. A chord matches if exactly its keys
+/// are held, ignoring the modifier keys in [`Code::MODIFIERS`].
+///
+/// ```rust
+/// # use keyboard_types::{ChordSet, Code, KeyboardEvent, KeyboardState};
+/// let mut chords = ChordSet::new();
+/// chords.insert(&[Code::KeyJ, Code::KeyK], "escape");
+///
+/// let mut state = KeyboardState::new();
+/// state.process(&KeyboardEvent::key_down('j', Code::KeyJ));
+/// assert_eq!(chords.get(&state), None);
+/// state.process(&KeyboardEvent::key_down('k', Code::KeyK));
+/// assert_eq!(chords.get(&state), Some(&"escape"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ChordSet<T> {
+    chords: Vec<(Vec<Code>, T)>,
+}
+
+impl<T> ChordSet<T> {
+    /// Create an empty set.
+    pub fn new() -> ChordSet<T> {
+        ChordSet { chords: Vec::new() }
+    }
+
+    /// Bind an action to the keys pressed together.
+    ///
+    /// Modifier keys and duplicates in `codes` are ignored. If a chord is
+    /// bound more than once the first binding is used.
+    pub fn insert(&mut self, codes: &[Code], action: T) {
+        let mut chord: Vec<Code> = Vec::new();
+        for &code in codes {
+            if !Code::MODIFIERS.contains(&code) && !chord.contains(&code) {
+                chord.push(code);
+            }
+        }
+        self.chords.push((chord, action));
+    }
+
+    /// Get the action bound to the keys held in the state.
+    ///
+    /// Returns `None` if the held keys are only part of a chord or if other
+    /// keys are held as well.
+    pub fn get(&self, state: &KeyboardState) -> Option<&T> {
+        let held = state
+            .pressed()
+            .iter()
+            .filter(|code| !Code::MODIFIERS.contains(code))
+            .count();
+        self.chords
+            .iter()
+            .find(|(chord, _)| {
+                !chord.is_empty()
+                    && chord.len() == held
+                    && chord.iter().all(|&code| state.is_pressed(code))
+            })
+            .map(|(_, action)| action)
+    }
+}
+
+impl<T> Default for ChordSet<T> {
+    fn default() -> Self {
+        ChordSet::new()
+    }
+}
+
+/// Compare keys like the shortcut matchers do: a single ASCII letter
+/// matches regardless of case, all other keys must be equal.
+fn same_key(a: &Key, b: &Key) -> bool {
+    match (a, b) {
+        (Key::Character(a), Key::Character(b)) => same_text(a, b),
+        _ => a == b,
+    }
+}
+
+fn same_text(a: &str, b: &str) -> bool {
+    a == b || (a.len() == 1 && a.as_bytes()[0].is_ascii_alphabetic() && a.eq_ignore_ascii_case(b))
+}
+
+/// Parse a shortcut like `Ctrl+Shift+P` into modifiers and a key.
+///
+/// The last `+`-separated part is parsed as the [`Key`], all parts before it
+/// as [`Modifiers`]. The key is either a character like `A` or the name of
+/// a [`NamedKey`] like `Enter` or `F5`.
+///
+/// Letters keep the case they were written in. The shortcut matchers
+/// compare single ASCII letters ignoring case, so `Ctrl+P` and `Ctrl+p`
+/// match the same events.
+///
+/// To use `+` as the key write it after a separator, for example `Ctrl++`,
+/// or write it as `Plus`, for example `Ctrl+Plus`. A lone `+` is the key
+/// without modifiers.
+///
+/// ```rust
+/// # use keyboard_types::{parse_shortcut, Key, Modifiers, NamedKey};
+/// let (modifiers, key) = parse_shortcut("Ctrl+Shift+P").unwrap();
+/// assert_eq!(modifiers, Modifiers::CONTROL | Modifiers::SHIFT);
+/// assert_eq!(key, Key::Character("P".to_owned()));
+///
+/// let (modifiers, key) = parse_shortcut("F5").unwrap();
+/// assert_eq!(modifiers, Modifiers::empty());
+/// assert_eq!(key, Key::Named(NamedKey::F5));
+/// ```
+pub fn parse_shortcut(s: &str) -> Result<(Modifiers, Key), ParseShortcutError> {
+    let (modifiers, key) = if let Some(modifiers) = s.strip_suffix("++") {
+        (modifiers, "+")
+    } else if s == "+" {
+        ("", s)
+    } else {
+        match s.rfind('+') {
+            Some(i) => (&s[..i], &s[i + 1..]),
+            None => ("", s),
+        }
+    };
+    if key.is_empty() {
+        return Err(ParseShortcutError::MissingKey);
+    }
+    let key = if key == "Plus" { "+" } else { key };
+    let modifiers = modifiers.parse().map_err(ParseShortcutError::Modifier)?;
+    let key = key.parse().map_err(ParseShortcutError::Key)?;
+    Ok((modifiers, key))
+}
+
+/// Error returned by [`parse_shortcut`].
+#[derive(Clone, Debug)]
+pub enum ParseShortcutError {
+    /// There is no key after the last `+`.
+    MissingKey,
+    /// One of the modifiers is not recognized.
+    Modifier(UnrecognizedModifierError),
+    /// The key is not recognized.
+    Key(UnrecognizedKeyError),
+}
+
+impl fmt::Display for ParseShortcutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseShortcutError::MissingKey => write!(f, "Missing key in shortcut"),
+            ParseShortcutError::Modifier(err) => err.fmt(f),
+            ParseShortcutError::Key(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for ParseShortcutError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseShortcutError::MissingKey => None,
+            ParseShortcutError::Modifier(err) => Some(err),
+            ParseShortcutError::Key(err) => Some(err),
+        }
+    }
+}
+
+/// A keyboard shortcut: modifiers and a single key pressed together.
+///
+/// Parsed with [`parse_shortcut`] and formatted in the same syntax with the
+/// modifier names from the specification, like `Control+Shift+P`.
+///
+/// ```rust
+/// # use keyboard_types::{Code, KeyboardEvent, Modifiers, Shortcut};
+/// let shortcut: Shortcut = "Ctrl+S".parse().unwrap();
+/// assert_eq!(shortcut.to_string(), "Control+S");
+///
+/// let event = KeyboardEvent {
+///     modifiers: Modifiers::CONTROL,
+///     ..KeyboardEvent::key_down('s', Code::KeyS)
+/// };
+/// assert!(shortcut.matches(&event));
+/// ```
+///
+/// Two shortcuts are equal if they match the same events, see
+/// [`Shortcut::matches`]. So `Ctrl+S` equals `Ctrl+s`.
+#[derive(Clone, Debug)]
+pub struct Shortcut {
+    /// The modifiers that must be held.
+    pub modifiers: Modifiers,
+    /// The key that triggers the shortcut.
+    pub key: Key,
+}
+
+impl Shortcut {
+    /// Create a shortcut.
+    pub fn new(modifiers: Modifiers, key: impl Into<Key>) -> Shortcut {
+        Shortcut {
+            modifiers,
+            key: key.into(),
+        }
+    }
+
+    /// Return `true` if the event is a key down event that triggers the shortcut.
+    ///
+    /// Like [`ShortcutMatcher`] only the shift, control, alt and meta
+    /// modifiers are compared and single ASCII letters are compared ignoring case.
+    pub fn matches(&self, event: &KeyboardEvent) -> bool {
+        event.state.is_down()
+            && event.modifiers.shortcut_only() == self.modifiers.shortcut_only()
+            && same_key(&self.key, &event.key)
+    }
+}
+
+impl PartialEq for Shortcut {
+    fn eq(&self, other: &Shortcut) -> bool {
+        self.modifiers.shortcut_only() == other.modifiers.shortcut_only()
+            && same_key(&self.key, &other.key)
+    }
+}
+
+impl Eq for Shortcut {}
+
+impl Hash for Shortcut {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.modifiers.shortcut_only().hash(state);
+        match self.key {
+            Key::Character(ref c) if c.len() == 1 && c.as_bytes()[0].is_ascii_alphabetic() => {
+                c.as_bytes()[0].to_ascii_lowercase().hash(state);
+            }
+            ref key => key.hash(state),
+        }
+    }
+}
+
+impl FromStr for Shortcut {
+    type Err = ParseShortcutError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (modifiers, key) = parse_shortcut(s)?;
+        Ok(Shortcut { modifiers, key })
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !self.modifiers.is_empty() {
+            write!(f, "{}+", self.modifiers)?;
+        }
+        write!(f, "{}", self.key)
+    }
+}
+
 pub trait MatchKey {
     fn match_key(&self, key: &Key) -> bool;
 }
 
 impl MatchKey for Key {
     fn match_key(&self, key: &Key) -> bool {
-        self == key
+        same_key(self, key)
     }
 }
 
@@ -171,9 +587,229 @@ impl MatchKey for char {
         match key {
             Key::Character(text) => {
                 let mut buf = [0; 4];
-                text.eq_ignore_ascii_case(self.encode_utf8(&mut buf))
+                same_text(text, self.encode_utf8(&mut buf))
             }
             _ => false,
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn shortcut_named() {
+        let event = KeyboardEvent::key_down(NamedKey::F5, Code::F5);
+        let matched = ShortcutMatcher::from_event(event)
+            .shortcut_named(Modifiers::empty(), NamedKey::F4, || 4)
+            .shortcut_named(Modifiers::empty(), NamedKey::F5, || 5)
+            .otherwise(|| 0);
+        assert_eq!(matched, Some(5));
+    }
+
+    #[test]
+    fn modifier_pattern() {
+        let pattern = ModifierPattern::new(Modifiers::CONTROL, Modifiers::ALT);
+        assert!(pattern.matches(Modifiers::CONTROL));
+        assert!(pattern.matches(Modifiers::CONTROL | Modifiers::SHIFT));
+        assert!(!pattern.matches(Modifiers::SHIFT));
+        assert!(!pattern.matches(Modifiers::CONTROL | Modifiers::ALT));
+
+        let exact = ModifierPattern::exact(Modifiers::CONTROL);
+        assert!(exact.matches(Modifiers::CONTROL));
+        assert!(!exact.matches(Modifiers::CONTROL | Modifiers::SHIFT));
+
+        let event = KeyboardEvent {
+            modifiers: Modifiers::CONTROL | Modifiers::SHIFT,
+            ..KeyboardEvent::key_down(NamedKey::F5, Code::F5)
+        };
+        let matched = ShortcutMatcher::from_event(event)
+            .shortcut_named(Modifiers::CONTROL, NamedKey::F5, || 1)
+            .shortcut_pattern(pattern, Key::Named(NamedKey::F5), || 2)
+            .otherwise(|| 0);
+        assert_eq!(matched, Some(2));
+    }
+
+    #[test]
+    fn parse() {
+        use alloc::string::ToString;
+
+        let (modifiers, key) = parse_shortcut("Ctrl+Shift+P").unwrap();
+        assert_eq!(modifiers, Modifiers::CONTROL | Modifiers::SHIFT);
+        assert_eq!(key, Key::Character("P".to_string()));
+        let (modifiers, key) = parse_shortcut("F5").unwrap();
+        assert_eq!(modifiers, Modifiers::empty());
+        assert_eq!(key, Key::Named(NamedKey::F5));
+        let (modifiers, key) = parse_shortcut("Ctrl++").unwrap();
+        assert_eq!(modifiers, Modifiers::CONTROL);
+        assert_eq!(key, Key::Character("+".to_string()));
+
+        let plus = Key::Character("+".to_string());
+        for shortcut in ["Ctrl++", "Ctrl+Plus"] {
+            let (modifiers, key) = parse_shortcut(shortcut).unwrap();
+            assert_eq!(modifiers, Modifiers::CONTROL);
+            assert_eq!(key, plus);
+        }
+        let (modifiers, key) = parse_shortcut("Ctrl+Shift++").unwrap();
+        assert_eq!(modifiers, Modifiers::CONTROL | Modifiers::SHIFT);
+        assert_eq!(key, plus);
+        let (modifiers, key) = parse_shortcut("+").unwrap();
+        assert_eq!(modifiers, Modifiers::empty());
+        assert_eq!(key, plus);
+        let (modifiers, key) = parse_shortcut("Ctrl+Enter").unwrap();
+        assert_eq!(modifiers, Modifiers::CONTROL);
+        assert_eq!(key, Key::Named(NamedKey::Enter));
+
+        assert!(matches!(
+            parse_shortcut("Ctrl+"),
+            Err(ParseShortcutError::MissingKey)
+        ));
+        assert!(matches!(
+            parse_shortcut("Ctlr+P"),
+            Err(ParseShortcutError::Modifier(_))
+        ));
+        assert!(matches!(
+            parse_shortcut("Ctrl+Ennter"),
+            Err(ParseShortcutError::Key(_))
+        ));
+    }
+
+    #[test]
+    fn conflicts() {
+        use alloc::string::ToString;
+
+        let mut table = ShortcutTable::new();
+        table.insert(Modifiers::CONTROL, Key::Character("c".to_string()), 1);
+        table.insert(Modifiers::CONTROL, Key::Named(NamedKey::F5), 2);
+        table.insert(Modifiers::CONTROL, Key::Character("C".to_string()), 3);
+        assert_eq!(
+            table.conflicts(),
+            [(Modifiers::CONTROL, Key::Character("c".to_string()))]
+        );
+        assert_eq!(
+            table.get(Modifiers::CONTROL, &Key::Character("C".to_string())),
+            Some(&1)
+        );
+        assert_eq!(
+            table.get(Modifiers::CONTROL, &Key::Named(NamedKey::F5)),
+            Some(&2)
+        );
+    }
+
+    #[test]
+    fn chord_set() {
+        let mut chords = ChordSet::new();
+        chords.insert(&[Code::KeyJ, Code::KeyK], 1);
+        chords.insert(&[Code::KeyJ, Code::KeyK, Code::KeyL], 2);
+        let mut state = KeyboardState::new();
+        assert_eq!(chords.get(&state), None);
+
+        state.process(&KeyboardEvent::key_down('k', Code::KeyK));
+        assert_eq!(chords.get(&state), None);
+        state.process(&KeyboardEvent::key_down(NamedKey::Shift, Code::ShiftLeft));
+        state.process(&KeyboardEvent::key_down('J', Code::KeyJ));
+        assert_eq!(chords.get(&state), Some(&1));
+        state.process(&KeyboardEvent::key_down('L', Code::KeyL));
+        assert_eq!(chords.get(&state), Some(&2));
+        state.process(&KeyboardEvent::key_up('J', Code::KeyJ));
+        assert_eq!(chords.get(&state), None);
+    }
+
+    #[test]
+    fn shortcut() {
+        use alloc::string::ToString;
+
+        let shortcut: Shortcut = "Ctrl+Shift+P".parse().unwrap();
+        assert_eq!(
+            shortcut,
+            Shortcut::new(Modifiers::CONTROL | Modifiers::SHIFT, 'P')
+        );
+        for s in ["Control+Shift+P", "F5", "+", "Alt++", "Meta+Enter"] {
+            let shortcut: Shortcut = s.parse().unwrap();
+            assert_eq!(shortcut.to_string(), s);
+        }
+        assert!("Ctrl+".parse::<Shortcut>().is_err());
+
+        let event = KeyboardEvent {
+            modifiers: Modifiers::CONTROL | Modifiers::SHIFT | Modifiers::NUM_LOCK,
+            ..KeyboardEvent::key_down('p', Code::KeyP)
+        };
+        assert!(shortcut.matches(&event));
+        assert!(!shortcut.matches(&KeyboardEvent {
+            state: KeyState::Up,
+            ..event.clone()
+        }));
+        assert!(!shortcut.matches(&KeyboardEvent {
+            modifiers: Modifiers::CONTROL,
+            ..event
+        }));
+    }
+
+    #[test]
+    fn letter_case() {
+        use alloc::string::ToString;
+
+        let event = KeyboardEvent {
+            modifiers: Modifiers::CONTROL,
+            ..KeyboardEvent::key_down('p', Code::KeyP)
+        };
+        assert!('P'.match_key(&event.key));
+        assert!(Key::Character("P".to_string()).match_key(&event.key));
+        let upper = Key::Character("Ä".to_string());
+        assert!(!Key::Character("ä".to_string()).match_key(&upper));
+        assert!(!'ä'.match_key(&upper));
+        let text = Key::Character("Ab".to_string());
+        assert!(!Key::Character("aB".to_string()).match_key(&text));
+        assert!(text.match_key(&text));
+
+        let mut table = ShortcutTable::new();
+        table.insert(Modifiers::empty(), Key::Character("ab".to_string()), 1);
+        table.insert(Modifiers::empty(), Key::Character("AB".to_string()), 2);
+        assert!(table.conflicts().is_empty());
+    }
+
+    #[test]
+    fn parse_shortcut_matcher() {
+        let (modifiers, key) = parse_shortcut("Ctrl+Shift+P").unwrap();
+        for event_key in ['P', 'p'] {
+            let event = KeyboardEvent {
+                modifiers: Modifiers::CONTROL | Modifiers::SHIFT,
+                ..KeyboardEvent::key_down(event_key, Code::KeyP)
+            };
+            let matched = ShortcutMatcher::from_event(event)
+                .shortcut(modifiers, key.clone(), || true)
+                .otherwise(|| false);
+            assert_eq!(matched, Some(true));
+        }
+    }
+
+    #[test]
+    fn shortcut_eq() {
+        let lower: Shortcut = "Ctrl+s".parse().unwrap();
+        let upper: Shortcut = "Ctrl+S".parse().unwrap();
+        assert_eq!(lower, upper);
+        assert_eq!(
+            Shortcut::new(Modifiers::CONTROL | Modifiers::NUM_LOCK, 's'),
+            upper
+        );
+        assert_ne!(Shortcut::new(Modifiers::CONTROL, 'd'), upper);
+        assert_ne!(Shortcut::new(Modifiers::ALT, 's'), upper);
+        assert_ne!(
+            Shortcut::new(Modifiers::empty(), Key::Character("ab".into())),
+            Shortcut::new(Modifiers::empty(), Key::Character("AB".into()))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn shortcut_hash() {
+        use std::collections::HashSet;
+
+        let mut shortcuts = HashSet::new();
+        shortcuts.insert("Ctrl+S".parse::<Shortcut>().unwrap());
+        assert!(shortcuts.contains(&"Control+s".parse::<Shortcut>().unwrap()));
+        assert!(!shortcuts.insert(Shortcut::new(Modifiers::CONTROL, 's')));
+        assert!(shortcuts.insert(Shortcut::new(Modifiers::CONTROL, NamedKey::Enter)));
+    }
+}
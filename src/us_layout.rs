@@ -0,0 +1,255 @@
+use core::str::FromStr;
+
+use crate::{Code, Key, Modifiers, NamedKey};
+
+/// The characters of the keys in the writing system section of the US
+/// QWERTY layout, without and with shift. Letters are listed separately.
+const US_CHARACTERS: &[(Code, char, char)] = &[
+    (Code::Backquote, '`', '~'),
+    (Code::Digit1, '1', '!'),
+    (Code::Digit2, '2', '@'),
+    (Code::Digit3, '3', '#'),
+    (Code::Digit4, '4', '$'),
+    (Code::Digit5, '5', '%'),
+    (Code::Digit6, '6', '^'),
+    (Code::Digit7, '7', '&'),
+    (Code::Digit8, '8', '*'),
+    (Code::Digit9, '9', '('),
+    (Code::Digit0, '0', ')'),
+    (Code::Minus, '-', '_'),
+    (Code::Equal, '=', '+'),
+    (Code::BracketLeft, '[', '{'),
+    (Code::BracketRight, ']', '}'),
+    (Code::Backslash, '\\', '|'),
+    (Code::Semicolon, ';', ':'),
+    (Code::Quote, '\'', '"'),
+    (Code::Comma, ',', '<'),
+    (Code::Period, '.', '>'),
+    (Code::Slash, '/', '?'),
+    (Code::Space, ' ', ' '),
+    (Code::NumpadAdd, '+', '+'),
+    (Code::NumpadSubtract, '-', '-'),
+    (Code::NumpadMultiply, '*', '*'),
+    (Code::NumpadDivide, '/', '/'),
+];
+
+impl Code {
+    /// Guess the code of the key that produces a printable ASCII character.
+    ///
+    /// This is a heuristic for input without physical key information, like
+    /// the bytes read by a terminal application. It assumes the US QWERTY
+    /// layout and the main keyboard area, so both `'a'` and `'A'` give
+    /// [`Code::KeyA`] and both `'1'` and `'!'` give [`Code::Digit1`].
+    ///
+    /// Returns `None` for control and non-ASCII characters.
+    pub fn from_ascii_char(c: char) -> Option<Code> {
+        if c.is_ascii_alphabetic() {
+            let upper = c.to_ascii_uppercase();
+            return Code::all()
+                .iter()
+                .copied()
+                .find(|&code| letter(code) == Some(upper));
+        }
+        US_CHARACTERS
+            .iter()
+            .find(|&&(_, normal, shifted)| c == normal || c == shifted)
+            .map(|&(code, _, _)| code)
+    }
+
+    /// Return `true` if the key fires the legacy `keypress` event.
+    ///
+    /// Browsers only fire `keypress` for keys that produce a character and
+    /// for <kbd>Enter</kbd>. The result is based on the US QWERTY layout with
+    /// `NumLock` on: the letter, digit, punctuation and space keys, the numpad
+    /// digits and operators and both <kbd>Enter</kbd> keys.
+    ///
+    /// The `keypress` event is deprecated, this is only useful to simulate
+    /// the event sequence of a browser.
+    ///
+    /// Specification: <https://w3c.github.io/uievents/#event-type-keypress>
+    pub fn generates_keypress(self) -> bool {
+        matches!(self, Code::Enter | Code::NumpadEnter)
+            || self.numpad_with_numlock(true).is_some()
+            || US_CHARACTERS.iter().any(|&(code, _, _)| code == self)
+            || letter(self).is_some()
+    }
+}
+
+/// Check whether a key value is produced by a code on the US QWERTY layout.
+///
+/// Letters are uppercase if either [`Modifiers::SHIFT`] or
+/// [`Modifiers::CAPS_LOCK`] is set, other characters depend only on shift.
+/// The numpad digits depend on [`Modifiers::NUM_LOCK`], see
+/// [`Code::numpad_with_numlock`]. Named keys match the code with the same
+/// name (e.g. [`Code::Enter`] and [`NamedKey::Enter`]) and the modifier keys
+/// match both the left and right code. Other modifiers are ignored.
+///
+/// This is meant for tests of synthesized events, real layouts differ.
+///
+/// ```rust
+/// # use keyboard_types::{layout_consistent, Code, Key, Modifiers};
+/// let a = Key::Character("a".to_owned());
+/// assert!(layout_consistent(Code::KeyA, &a, Modifiers::empty()));
+/// assert!(!layout_consistent(Code::KeyA, &a, Modifiers::SHIFT));
+/// ```
+pub fn layout_consistent(code: Code, key: &Key, modifiers: Modifiers) -> bool {
+    if let Some(expected) = code.numpad_with_numlock(modifiers.contains(Modifiers::NUM_LOCK)) {
+        return *key == expected;
+    }
+    match key {
+        Key::Character(text) => {
+            let mut chars = text.chars();
+            let c = match (chars.next(), chars.next()) {
+                (Some(c), None) => c,
+                _ => return false,
+            };
+            if let Some(letter) = letter(code) {
+                if modifiers.shift() != modifiers.contains(Modifiers::CAPS_LOCK) {
+                    c == letter
+                } else {
+                    c == letter.to_ascii_lowercase()
+                }
+            } else {
+                US_CHARACTERS.iter().any(|&(k, unshifted, shifted)| {
+                    k == code
+                        && c == if modifiers.shift() {
+                            shifted
+                        } else {
+                            unshifted
+                        }
+                })
+            }
+        }
+        Key::Named(named) => {
+            let expected = match code {
+                Code::ShiftLeft | Code::ShiftRight => NamedKey::Shift,
+                Code::ControlLeft | Code::ControlRight => NamedKey::Control,
+                Code::AltLeft | Code::AltRight => NamedKey::Alt,
+                Code::MetaLeft | Code::MetaRight => NamedKey::Meta,
+                Code::NumpadEnter => NamedKey::Enter,
+                _ => match NamedKey::from_str(code.as_str()) {
+                    Ok(named) => named,
+                    Err(_) => return false,
+                },
+            };
+            *named == expected
+        }
+    }
+}
+
+/// The uppercase letter of the `KeyA` to `KeyZ` codes.
+fn letter(code: Code) -> Option<char> {
+    match code.as_str().as_bytes() {
+        [b'K', b'e', b'y', letter] => Some(char::from(*letter)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+
+    fn character(s: &str) -> Key {
+        Key::Character(s.to_string())
+    }
+
+    #[test]
+    fn characters() {
+        let none = Modifiers::empty();
+        assert!(layout_consistent(Code::KeyA, &character("a"), none));
+        assert!(!layout_consistent(Code::KeyA, &character("b"), none));
+        assert!(!layout_consistent(
+            Code::KeyA,
+            &character("b"),
+            Modifiers::SHIFT
+        ));
+        assert!(layout_consistent(
+            Code::KeyA,
+            &character("A"),
+            Modifiers::SHIFT
+        ));
+        assert!(layout_consistent(
+            Code::KeyA,
+            &character("A"),
+            Modifiers::CAPS_LOCK
+        ));
+        assert!(layout_consistent(
+            Code::Digit2,
+            &character("@"),
+            Modifiers::SHIFT
+        ));
+        assert!(!layout_consistent(Code::Digit2, &character("@"), none));
+        assert!(layout_consistent(Code::Space, &character(" "), none));
+        assert!(layout_consistent(
+            Code::Numpad1,
+            &character("1"),
+            Modifiers::NUM_LOCK
+        ));
+        assert!(!layout_consistent(Code::Numpad1, &character("1"), none));
+    }
+
+    #[test]
+    fn from_ascii_char() {
+        assert_eq!(Code::from_ascii_char('a'), Some(Code::KeyA));
+        assert_eq!(Code::from_ascii_char('A'), Some(Code::KeyA));
+        assert_eq!(Code::from_ascii_char('z'), Some(Code::KeyZ));
+        assert_eq!(Code::from_ascii_char('1'), Some(Code::Digit1));
+        assert_eq!(Code::from_ascii_char('0'), Some(Code::Digit0));
+        assert_eq!(Code::from_ascii_char('!'), Some(Code::Digit1));
+        assert_eq!(Code::from_ascii_char('?'), Some(Code::Slash));
+        assert_eq!(Code::from_ascii_char('"'), Some(Code::Quote));
+        assert_eq!(Code::from_ascii_char('+'), Some(Code::Equal));
+        assert_eq!(Code::from_ascii_char(' '), Some(Code::Space));
+        assert_eq!(Code::from_ascii_char('\n'), None);
+        assert_eq!(Code::from_ascii_char('é'), None);
+    }
+
+    #[test]
+    fn generates_keypress() {
+        assert!(Code::KeyA.generates_keypress());
+        assert!(Code::Digit1.generates_keypress());
+        assert!(Code::Space.generates_keypress());
+        assert!(Code::Enter.generates_keypress());
+        assert!(Code::NumpadDecimal.generates_keypress());
+        assert!(!Code::ArrowUp.generates_keypress());
+        assert!(!Code::ShiftLeft.generates_keypress());
+        assert!(!Code::KeyboardBacklightToggle.generates_keypress());
+    }
+
+    #[test]
+    fn named_keys() {
+        let none = Modifiers::empty();
+        assert!(layout_consistent(
+            Code::Enter,
+            &Key::Named(NamedKey::Enter),
+            none
+        ));
+        assert!(layout_consistent(
+            Code::NumpadEnter,
+            &Key::Named(NamedKey::Enter),
+            none
+        ));
+        assert!(layout_consistent(
+            Code::ShiftRight,
+            &Key::Named(NamedKey::Shift),
+            none
+        ));
+        assert!(layout_consistent(
+            Code::Numpad1,
+            &Key::Named(NamedKey::End),
+            none
+        ));
+        assert!(!layout_consistent(
+            Code::KeyA,
+            &Key::Named(NamedKey::Enter),
+            none
+        ));
+        assert!(!layout_consistent(Code::Enter, &character("\n"), none));
+        assert!(!layout_consistent(
+            Code::KeyboardBacklightToggle,
+            &character("b"),
+            none
+        ));
+    }
+}
@@ -0,0 +1,100 @@
+//! Decoding of characters that arrive as UTF-16 code units.
+
+use crate::{Key, NamedKey};
+
+/// Decode characters from a stream of UTF-16 code units.
+///
+/// Windows delivers characters as UTF-16 code units with `WM_CHAR`, so
+/// characters outside of the Basic Multilingual Plane arrive as a surrogate
+/// pair in two messages. The decoder keeps the high surrogate until the low
+/// surrogate arrives.
+///
+/// ```rust
+/// # use keyboard_types::{Key, Utf16KeyDecoder};
+/// let mut decoder = Utf16KeyDecoder::new();
+/// assert_eq!(decoder.push(0xD83D), None);
+/// assert_eq!(decoder.push(0xDE00), Some(Key::Character("😀".to_owned())));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Utf16KeyDecoder {
+    high_surrogate: Option<u16>,
+}
+
+impl Utf16KeyDecoder {
+    /// Create a decoder without a pending high surrogate.
+    pub fn new() -> Utf16KeyDecoder {
+        Utf16KeyDecoder {
+            high_surrogate: None,
+        }
+    }
+
+    /// Decode the next code unit.
+    ///
+    /// Returns a [`Key::Character`] once a complete character was received.
+    /// Unpaired surrogates are dropped: a low surrogate without a preceding
+    /// high surrogate is ignored and a high surrogate replaces a pending one.
+    ///
+    /// The control characters sent for <kbd>Backspace</kbd>, <kbd>Tab</kbd>,
+    /// <kbd>Enter</kbd> and <kbd>Escape</kbd> become the corresponding
+    /// [`Key::Named`]. Other control characters are ignored.
+    pub fn push(&mut self, unit: u16) -> Option<Key> {
+        match unit {
+            0xD800..=0xDBFF => {
+                self.high_surrogate = Some(unit);
+                None
+            }
+            0xDC00..=0xDFFF => {
+                let high = self.high_surrogate.take()?;
+                char::decode_utf16([high, unit]).next()?.ok().map(Key::from)
+            }
+            _ => {
+                self.high_surrogate = None;
+                match unit {
+                    0x08 => Some(Key::Named(NamedKey::Backspace)),
+                    0x09 => Some(Key::Named(NamedKey::Tab)),
+                    0x0D => Some(Key::Named(NamedKey::Enter)),
+                    0x1B => Some(Key::Named(NamedKey::Escape)),
+                    _ => char::from_u32(u32::from(unit))
+                        .filter(|c| !c.is_control())
+                        .map(Key::from),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn push() {
+        let mut decoder = Utf16KeyDecoder::new();
+        assert_eq!(decoder.push(0x00E9), Some(Key::Character("é".to_string())));
+        assert_eq!(decoder.push(0xD83D), None);
+        assert_eq!(
+            decoder.push(0xDE00),
+            Some(Key::Character("\u{1F600}".to_string()))
+        );
+        assert_eq!(decoder.push(0xDE00), None);
+        assert_eq!(decoder.push(0xD83D), None);
+        assert_eq!(decoder.push(0x0061), Some(Key::Character("a".to_string())));
+        assert_eq!(decoder.push(0xDE00), None);
+    }
+
+    #[test]
+    fn control_characters() {
+        let mut decoder = Utf16KeyDecoder::new();
+        assert_eq!(decoder.push(0x08), Some(Key::Named(NamedKey::Backspace)));
+        assert_eq!(decoder.push(0x09), Some(Key::Named(NamedKey::Tab)));
+        assert_eq!(decoder.push(0x0D), Some(Key::Named(NamedKey::Enter)));
+        assert_eq!(decoder.push(0x1B), Some(Key::Named(NamedKey::Escape)));
+        assert_eq!(decoder.push(0x00), None);
+        assert_eq!(decoder.push(0x01), None);
+        assert_eq!(decoder.push(0x7F), None);
+        assert_eq!(decoder.push(0xD83D), None);
+        assert_eq!(decoder.push(0x0D), Some(Key::Named(NamedKey::Enter)));
+        assert_eq!(decoder.push(0xDE00), None);
+    }
+}
@@ -0,0 +1,134 @@
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use core::error::Error;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::{Code, KeyState, KeyboardEvent};
+
+/// Check that a stream of keyboard events is well-formed.
+///
+/// Keys are identified by their [`Code`]. Events with
+/// [`Code::Unidentified`] are not checked, since different keys can share it.
+///
+/// ```rust
+/// # use keyboard_types::{Code, KeyEventError, KeyEventValidator, KeyboardEvent, NamedKey};
+/// let mut validator = KeyEventValidator::new();
+/// let up = KeyboardEvent::key_up(NamedKey::Enter, Code::Enter);
+/// assert_eq!(validator.feed(&up), Err(KeyEventError::UnmatchedKeyUp(Code::Enter)));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct KeyEventValidator {
+    pressed: Vec<Code>,
+}
+
+impl KeyEventValidator {
+    /// Create a validator with no keys pressed.
+    pub fn new() -> KeyEventValidator {
+        KeyEventValidator {
+            pressed: Vec::new(),
+        }
+    }
+
+    /// Check the next event of the stream.
+    ///
+    /// Invalid events are reported but still update the set of pressed keys,
+    /// so a single backend bug does not cause a cascade of errors.
+    pub fn feed(&mut self, event: &KeyboardEvent) -> Result<(), KeyEventError> {
+        if event.code == Code::Unidentified {
+            return Ok(());
+        }
+        let index = self.pressed.iter().position(|&code| code == event.code);
+        match (event.state, event.repeat, index) {
+            (KeyState::Down, false, None) => {
+                self.pressed.push(event.code);
+                Ok(())
+            }
+            (KeyState::Down, false, Some(_)) => Err(KeyEventError::DuplicateKeyDown(event.code)),
+            (KeyState::Down, true, Some(_)) => Ok(()),
+            (KeyState::Down, true, None) => {
+                self.pressed.push(event.code);
+                Err(KeyEventError::UnexpectedRepeat(event.code))
+            }
+            (KeyState::Up, repeat, Some(index)) => {
+                self.pressed.swap_remove(index);
+                if repeat {
+                    Err(KeyEventError::UnexpectedRepeat(event.code))
+                } else {
+                    Ok(())
+                }
+            }
+            (KeyState::Up, _, None) => Err(KeyEventError::UnmatchedKeyUp(event.code)),
+        }
+    }
+}
+
+/// Error returned by [`KeyEventValidator::feed`] for a malformed event.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum KeyEventError {
+    /// A key-up event without a prior key-down event for the same code.
+    UnmatchedKeyUp(Code),
+    /// A key-down event without repeat flag for a key that is already pressed.
+    DuplicateKeyDown(Code),
+    /// A repeated event for a key that is not pressed, or a key-up event
+    /// with the repeat flag.
+    UnexpectedRepeat(Code),
+}
+
+impl fmt::Display for KeyEventError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            KeyEventError::UnmatchedKeyUp(code) => {
+                write!(f, "Key up without key down: {}", code)
+            }
+            KeyEventError::DuplicateKeyDown(code) => {
+                write!(f, "Key down for pressed key: {}", code)
+            }
+            KeyEventError::UnexpectedRepeat(code) => {
+                write!(f, "Unexpected repeat: {}", code)
+            }
+        }
+    }
+}
+
+impl Error for KeyEventError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::NamedKey;
+
+    #[test]
+    fn orphan_key_up() {
+        let mut validator = KeyEventValidator::new();
+        let up = KeyboardEvent::key_up(NamedKey::Shift, Code::ShiftLeft);
+        assert_eq!(
+            validator.feed(&up),
+            Err(KeyEventError::UnmatchedKeyUp(Code::ShiftLeft))
+        );
+    }
+
+    #[test]
+    fn sequence() {
+        let mut validator = KeyEventValidator::new();
+        let down = KeyboardEvent::key_down(NamedKey::Enter, Code::Enter);
+        let repeat = KeyboardEvent {
+            repeat: true,
+            ..down.clone()
+        };
+        let up = KeyboardEvent::key_up(NamedKey::Enter, Code::Enter);
+        assert_eq!(validator.feed(&down), Ok(()));
+        assert_eq!(validator.feed(&repeat), Ok(()));
+        assert_eq!(
+            validator.feed(&down),
+            Err(KeyEventError::DuplicateKeyDown(Code::Enter))
+        );
+        assert_eq!(validator.feed(&up), Ok(()));
+        assert_eq!(
+            validator.feed(&repeat),
+            Err(KeyEventError::UnexpectedRepeat(Code::Enter))
+        );
+    }
+}
@@ -0,0 +1,57 @@
+//! Conversion from the DOM `KeyboardEvent` of [`web_sys`].
+//!
+//! Only available when compiling for `wasm32` with the `wasm` feature.
+
+use core::str::FromStr;
+
+use crate::{Code, Key, KeyState, KeyboardEvent, Location, Modifiers};
+
+impl KeyboardEvent {
+    /// Read a keyboard event from a DOM `KeyboardEvent`.
+    ///
+    /// The state is [`KeyState::Up`] for `keyup` events and
+    /// [`KeyState::Down`] otherwise. Unknown key and code values become
+    /// [`NamedKey::Unidentified`](crate::NamedKey::Unidentified) and
+    /// [`Code::Unidentified`]. The timestamp is the DOM `timeStamp` in
+    /// milliseconds.
+    pub fn from_web_sys(event: &web_sys::KeyboardEvent) -> KeyboardEvent {
+        let state = if event.type_() == "keyup" {
+            KeyState::Up
+        } else {
+            KeyState::Down
+        };
+        let location = match event.location() {
+            web_sys::KeyboardEvent::DOM_KEY_LOCATION_LEFT => Location::Left,
+            web_sys::KeyboardEvent::DOM_KEY_LOCATION_RIGHT => Location::Right,
+            web_sys::KeyboardEvent::DOM_KEY_LOCATION_NUMPAD => Location::Numpad,
+            _ => Location::Standard,
+        };
+        let mut modifiers = Modifiers::empty();
+        modifiers.set(Modifiers::ALT, event.alt_key());
+        modifiers.set(Modifiers::CONTROL, event.ctrl_key());
+        modifiers.set(Modifiers::META, event.meta_key());
+        modifiers.set(Modifiers::SHIFT, event.shift_key());
+        for (modifier, name) in [
+            (Modifiers::ALT_GRAPH, "AltGraph"),
+            (Modifiers::CAPS_LOCK, "CapsLock"),
+            (Modifiers::FN, "Fn"),
+            (Modifiers::FN_LOCK, "FnLock"),
+            (Modifiers::NUM_LOCK, "NumLock"),
+            (Modifiers::SCROLL_LOCK, "ScrollLock"),
+            (Modifiers::SYMBOL, "Symbol"),
+            (Modifiers::SYMBOL_LOCK, "SymbolLock"),
+        ] {
+            modifiers.set(modifier, event.get_modifier_state(name));
+        }
+        KeyboardEvent {
+            state,
+            key: Key::from_text(&event.key()),
+            code: Code::from_str(&event.code()).unwrap_or(Code::Unidentified),
+            location,
+            modifiers,
+            repeat: event.repeat(),
+            is_composing: event.is_composing(),
+            timestamp: Some(event.time_stamp() as u64),
+        }
+    }
+}
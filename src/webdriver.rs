@@ -349,6 +349,7 @@ impl KeyInputState {
             modifiers: self.modifiers,
             repeat,
             is_composing: false,
+            timestamp: None,
         }
     }
 
@@ -374,6 +375,7 @@ impl KeyInputState {
             modifiers: self.modifiers,
             repeat: false,
             is_composing: false,
+            timestamp: None,
         })
     }
 
@@ -515,6 +517,75 @@ pub fn send_keys(text: &str) -> Vec<Event> {
     result
 }
 
+/// Find the WebDriver code point for the key and code of an event.
+///
+/// Falls back to the first code point with the same key if no code point
+/// matches the code as well, e.g. for events without a code.
+fn raw_key(event: &KeyboardEvent) -> Option<char> {
+    let mut raw_keys = '\u{E001}'..='\u{E05D}';
+    raw_keys
+        .clone()
+        .find(|&raw| normalised_key_value(raw) == event.key && code(raw) == event.code)
+        .or_else(|| raw_keys.find(|&raw| normalised_key_value(raw) == event.key))
+}
+
+/// Compute a WebDriver *Element Send Keys* string from keyboard events.
+///
+/// This is the inverse of [`send_keys`]. Characters are written as text
+/// unless they were typed on the numpad. Named keys are written as their
+/// WebDriver code point and are skipped if there is none. If the code of a
+/// named key does not match, e.g. [`Code::Unidentified`], the code point is
+/// found by the key alone. Key up events are
+/// ignored, except for the release of a modifier, which is written as the
+/// null key `\u{E000}` that releases all modifiers.
+///
+/// The <kbd>Shift</kbd> key is not written, as the character values already
+/// contain the shifted text and [`send_keys`] presses <kbd>Shift</kbd> itself.
+///
+/// ```rust
+/// # use keyboard_types::webdriver::*;
+/// let events: Vec<_> = send_keys("Hello\u{E006}")
+///     .into_iter()
+///     .filter_map(|event| match event {
+///         Event::Keyboard(event) => Some(event),
+///         Event::Composition(_) => None,
+///     })
+///     .collect();
+/// assert_eq!(keys_from_events(&events), "Hello\u{E006}");
+/// ```
+pub fn keys_from_events(events: &[KeyboardEvent]) -> String {
+    let mut keys = String::new();
+    let mut modifiers_written = false;
+    for event in events {
+        if event.key == Key::Named(NamedKey::Shift) {
+            continue;
+        }
+        if event.state == KeyState::Up {
+            if !get_modifier(&event.key).is_empty() && modifiers_written {
+                keys.push('\u{E000}');
+                modifiers_written = false;
+            }
+            continue;
+        }
+        if let Key::Character(text) = &event.key {
+            match raw_key(event) {
+                Some(raw) if event.location == Location::Numpad => keys.push(raw),
+                _ => keys.push_str(text),
+            }
+            continue;
+        }
+        let raw = match raw_key(event) {
+            Some(raw) => raw,
+            None => continue,
+        };
+        keys.push(raw);
+        if !get_modifier(&event.key).is_empty() {
+            modifiers_written = true;
+        }
+    }
+    keys
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -539,4 +610,42 @@ mod test {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn keys_from_events() {
+        fn keyboard_events(text: &str) -> Vec<KeyboardEvent> {
+            send_keys(text)
+                .into_iter()
+                .filter_map(|event| match event {
+                    Event::Keyboard(event) => Some(event),
+                    Event::Composition(_) => None,
+                })
+                .collect()
+        }
+
+        for text in [
+            "ab\u{E006}\u{E009}a\u{E000}",
+            "Hi!",
+            "1\u{E01B}\u{E007}",
+            " \u{E012}",
+        ] {
+            assert_eq!(super::keys_from_events(&keyboard_events(text)), text);
+        }
+    }
+
+    #[test]
+    fn keys_from_events_without_code() {
+        let events = [
+            KeyboardEvent::key_down(Key::Character("a".to_string()), Code::Unidentified),
+            KeyboardEvent::key_down(NamedKey::Enter, Code::Unidentified),
+            KeyboardEvent::key_down(NamedKey::ArrowLeft, Code::Numpad4),
+            KeyboardEvent::key_down(NamedKey::ArrowLeft, Code::KeyH),
+            KeyboardEvent::key_down(NamedKey::Control, Code::Unidentified),
+            KeyboardEvent::key_up(NamedKey::Control, Code::Unidentified),
+        ];
+        assert_eq!(
+            super::keys_from_events(&events),
+            "a\u{E006}\u{E058}\u{E012}\u{E009}\u{E000}"
+        );
+    }
 }
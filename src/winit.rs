@@ -0,0 +1,247 @@
+//! Conversions to and from the key types of [`winit`].
+//!
+//! The types of `winit` follow the UI Events specification as well,
+//! so most values have an equivalent with the same name.
+//!
+//! ```rust
+//! # use keyboard_types::{Code, Key, NamedKey};
+//! use winit::keyboard::KeyCode;
+//!
+//! assert_eq!(Code::from(KeyCode::KeyA), Code::KeyA);
+//! assert_eq!(KeyCode::try_from(Code::KeyA), Ok(KeyCode::KeyA));
+//!
+//! let key = winit::keyboard::Key::Named(winit::keyboard::NamedKey::Enter);
+//! assert_eq!(Key::from(key), Key::Named(NamedKey::Enter));
+//! ```
+
+#![allow(deprecated)]
+
+use alloc::string::ToString;
+use core::fmt;
+use std::error::Error;
+
+use winit::keyboard::{self as winit_keyboard, KeyCode, NativeKey, PhysicalKey};
+
+use crate::{Code, Key, NamedKey};
+
+/// Error returned when a value has no equivalent in the other crate.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NoEquivalentError;
+
+impl fmt::Display for NoEquivalentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "No equivalent key")
+    }
+}
+
+impl Error for NoEquivalentError {}
+
+macro_rules! convert_code {
+    ($($name:ident,)*) => {
+        impl TryFrom<Code> for KeyCode {
+            type Error = NoEquivalentError;
+
+            fn try_from(code: Code) -> Result<Self, Self::Error> {
+                Ok(match code {
+                    Code::MetaLeft => KeyCode::SuperLeft,
+                    Code::MetaRight => KeyCode::SuperRight,
+                    Code::Super => KeyCode::Meta,
+                    $(Code::$name => KeyCode::$name,)*
+                    _ => return Err(NoEquivalentError),
+                })
+            }
+        }
+
+        impl From<KeyCode> for Code {
+            fn from(code: KeyCode) -> Self {
+                match code {
+                    KeyCode::SuperLeft => Code::MetaLeft,
+                    KeyCode::SuperRight => Code::MetaRight,
+                    KeyCode::Meta => Code::Super,
+                    $(KeyCode::$name => Code::$name,)*
+                    _ => Code::Unidentified,
+                }
+            }
+        }
+    };
+}
+
+convert_code! {
+    Backquote, Backslash, BracketLeft, BracketRight, Comma, Digit0, Digit1, Digit2, Digit3,
+    Digit4, Digit5, Digit6, Digit7, Digit8, Digit9, Equal, IntlBackslash, IntlRo, IntlYen, KeyA,
+    KeyB, KeyC, KeyD, KeyE, KeyF, KeyG, KeyH, KeyI, KeyJ, KeyK, KeyL, KeyM, KeyN, KeyO, KeyP,
+    KeyQ, KeyR, KeyS, KeyT, KeyU, KeyV, KeyW, KeyX, KeyY, KeyZ, Minus, Period, Quote, Semicolon,
+    Slash, AltLeft, AltRight, Backspace, CapsLock, ContextMenu, ControlLeft, ControlRight,
+    Enter, ShiftLeft, ShiftRight, Space, Tab, Convert, KanaMode, Lang1, Lang2, Lang3, Lang4,
+    Lang5, NonConvert, Delete, End, Help, Home, Insert, PageDown, PageUp, ArrowDown, ArrowLeft,
+    ArrowRight, ArrowUp, NumLock, Numpad0, Numpad1, Numpad2, Numpad3, Numpad4, Numpad5, Numpad6,
+    Numpad7, Numpad8, Numpad9, NumpadAdd, NumpadBackspace, NumpadClear, NumpadClearEntry,
+    NumpadComma, NumpadDecimal, NumpadDivide, NumpadEnter, NumpadEqual, NumpadHash,
+    NumpadMemoryAdd, NumpadMemoryClear, NumpadMemoryRecall, NumpadMemoryStore,
+    NumpadMemorySubtract, NumpadMultiply, NumpadParenLeft, NumpadParenRight, NumpadStar,
+    NumpadSubtract, Escape, Fn, FnLock, PrintScreen, ScrollLock, Pause, BrowserBack,
+    BrowserFavorites, BrowserForward, BrowserHome, BrowserRefresh, BrowserSearch, BrowserStop,
+    Eject, LaunchApp1, LaunchApp2, LaunchMail, MediaPlayPause, MediaSelect, MediaStop,
+    MediaTrackNext, MediaTrackPrevious, Power, Sleep, AudioVolumeDown, AudioVolumeMute,
+    AudioVolumeUp, WakeUp, Hyper, Turbo, Abort, Resume, Suspend, Again, Copy, Cut, Find, Open,
+    Paste, Props, Select, Undo, Hiragana, Katakana, F1, F2, F3, F4, F5, F6, F7, F8, F9, F10,
+    F11, F12, F13, F14, F15, F16, F17, F18, F19, F20, F21, F22, F23, F24, F25, F26, F27, F28,
+    F29, F30, F31, F32, F33, F34, F35,
+}
+
+impl From<PhysicalKey> for Code {
+    fn from(key: PhysicalKey) -> Self {
+        match key {
+            PhysicalKey::Code(code) => code.into(),
+            PhysicalKey::Unidentified(_) => Code::Unidentified,
+        }
+    }
+}
+
+macro_rules! convert_named_key {
+    ($($name:ident,)*) => {
+        impl TryFrom<NamedKey> for winit_keyboard::NamedKey {
+            type Error = NoEquivalentError;
+
+            fn try_from(key: NamedKey) -> Result<Self, Self::Error> {
+                Ok(match key {
+                    $(NamedKey::$name => winit_keyboard::NamedKey::$name,)*
+                    _ => return Err(NoEquivalentError),
+                })
+            }
+        }
+
+        /// The `winit` [`Space`](winit_keyboard::NamedKey::Space) key is
+        /// converted to `Key::Character(" ")`.
+        impl From<winit_keyboard::NamedKey> for Key {
+            fn from(key: winit_keyboard::NamedKey) -> Self {
+                match key {
+                    winit_keyboard::NamedKey::Space => Key::Character(" ".to_string()),
+                    $(winit_keyboard::NamedKey::$name => Key::Named(NamedKey::$name),)*
+                    _ => Key::Named(NamedKey::Unidentified),
+                }
+            }
+        }
+    };
+}
+
+convert_named_key! {
+    Alt, AltGraph, CapsLock, Control, Fn, FnLock, Meta, NumLock, ScrollLock, Shift, Symbol,
+    SymbolLock, Hyper, Super, Enter, Tab, ArrowDown, ArrowLeft, ArrowRight, ArrowUp, End, Home,
+    PageDown, PageUp, Backspace, Clear, Copy, CrSel, Cut, Delete, EraseEof, ExSel, Insert,
+    Paste, Redo, Undo, Accept, Again, Attn, Cancel, ContextMenu, Escape, Execute, Find, Help,
+    Pause, Play, Props, Select, ZoomIn, ZoomOut, BrightnessDown, BrightnessUp, Eject, LogOff,
+    Power, PowerOff, PrintScreen, Hibernate, Standby, WakeUp, AllCandidates, Alphanumeric,
+    CodeInput, Compose, Convert, FinalMode, GroupFirst, GroupLast, GroupNext, GroupPrevious,
+    ModeChange, NextCandidate, NonConvert, PreviousCandidate, Process, SingleCandidate,
+    HangulMode, HanjaMode, JunjaMode, Eisu, Hankaku, Hiragana, HiraganaKatakana, KanaMode,
+    KanjiMode, Katakana, Romaji, Zenkaku, ZenkakuHankaku, Soft1, Soft2, Soft3, Soft4,
+    ChannelDown, ChannelUp, Close, MailForward, MailReply, MailSend, MediaClose,
+    MediaFastForward, MediaPause, MediaPlay, MediaPlayPause, MediaRecord, MediaRewind,
+    MediaStop, MediaTrackNext, MediaTrackPrevious, New, Open, Print, Save, SpellCheck, Key11,
+    Key12, AudioBalanceLeft, AudioBalanceRight, AudioBassBoostDown, AudioBassBoostToggle,
+    AudioBassBoostUp, AudioFaderFront, AudioFaderRear, AudioSurroundModeNext, AudioTrebleDown,
+    AudioTrebleUp, AudioVolumeDown, AudioVolumeUp, AudioVolumeMute, MicrophoneToggle,
+    MicrophoneVolumeDown, MicrophoneVolumeUp, MicrophoneVolumeMute, SpeechCorrectionList,
+    SpeechInputToggle, LaunchApplication1, LaunchApplication2, LaunchCalendar, LaunchContacts,
+    LaunchMail, LaunchMediaPlayer, LaunchMusicPlayer, LaunchPhone, LaunchScreenSaver,
+    LaunchSpreadsheet, LaunchWebBrowser, LaunchWebCam, LaunchWordProcessor, BrowserBack,
+    BrowserFavorites, BrowserForward, BrowserHome, BrowserRefresh, BrowserSearch, BrowserStop,
+    AppSwitch, Call, Camera, CameraFocus, EndCall, GoBack, GoHome, HeadsetHook,
+    LastNumberRedial, Notification, MannerMode, VoiceDial, TV, TV3DMode, TVAntennaCable,
+    TVAudioDescription, TVAudioDescriptionMixDown, TVAudioDescriptionMixUp, TVContentsMenu,
+    TVDataService, TVInput, TVInputComponent1, TVInputComponent2, TVInputComposite1,
+    TVInputComposite2, TVInputHDMI1, TVInputHDMI2, TVInputHDMI3, TVInputHDMI4, TVInputVGA1,
+    TVMediaContext, TVNetwork, TVNumberEntry, TVPower, TVRadioService, TVSatellite,
+    TVSatelliteBS, TVSatelliteCS, TVSatelliteToggle, TVTerrestrialAnalog, TVTerrestrialDigital,
+    TVTimer, AVRInput, AVRPower, ColorF0Red, ColorF1Green, ColorF2Yellow, ColorF3Blue,
+    ColorF4Grey, ColorF5Brown, ClosedCaptionToggle, Dimmer, DisplaySwap, DVR, Exit,
+    FavoriteClear0, FavoriteClear1, FavoriteClear2, FavoriteClear3, FavoriteRecall0,
+    FavoriteRecall1, FavoriteRecall2, FavoriteRecall3, FavoriteStore0, FavoriteStore1,
+    FavoriteStore2, FavoriteStore3, Guide, GuideNextDay, GuidePreviousDay, Info, InstantReplay,
+    Link, ListProgram, LiveContent, Lock, MediaApps, MediaAudioTrack, MediaLast,
+    MediaSkipBackward, MediaSkipForward, MediaStepBackward, MediaStepForward, MediaTopMenu,
+    NavigateIn, NavigateNext, NavigateOut, NavigatePrevious, NextFavoriteChannel,
+    NextUserProfile, OnDemand, Pairing, PinPDown, PinPMove, PinPToggle, PinPUp, PlaySpeedDown,
+    PlaySpeedReset, PlaySpeedUp, RandomToggle, RcLowBattery, RecordSpeedNext, RfBypass,
+    ScanChannelsToggle, ScreenModeNext, Settings, SplitScreenToggle, STBInput, STBPower,
+    Subtitle, Teletext, VideoModeNext, Wink, ZoomToggle, F1, F2, F3, F4, F5, F6, F7, F8, F9,
+    F10, F11, F12, F13, F14, F15, F16, F17, F18, F19, F20, F21, F22, F23, F24, F25, F26, F27,
+    F28, F29, F30, F31, F32, F33, F34, F35,
+}
+
+impl From<winit_keyboard::Key> for Key {
+    fn from(key: winit_keyboard::Key) -> Self {
+        match key {
+            winit_keyboard::Key::Named(key) => key.into(),
+            winit_keyboard::Key::Character(s) => Key::Character(s.to_string()),
+            winit_keyboard::Key::Unidentified(_) => Key::Named(NamedKey::Unidentified),
+            winit_keyboard::Key::Dead(_) => Key::Named(NamedKey::Dead),
+        }
+    }
+}
+
+/// `Key::Character(" ")` is converted to the `winit`
+/// [`Space`](winit_keyboard::NamedKey::Space) key.
+impl From<Key> for winit_keyboard::Key {
+    fn from(key: Key) -> Self {
+        match key {
+            Key::Character(s) if s == " " => {
+                winit_keyboard::Key::Named(winit_keyboard::NamedKey::Space)
+            }
+            Key::Character(s) => winit_keyboard::Key::Character(s.into()),
+            Key::Named(NamedKey::Dead) => winit_keyboard::Key::Dead(None),
+            Key::Named(key) => match key.try_into() {
+                Ok(key) => winit_keyboard::Key::Named(key),
+                Err(NoEquivalentError) => {
+                    winit_keyboard::Key::Unidentified(NativeKey::Unidentified)
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn letters() {
+        assert_eq!(Code::from(KeyCode::KeyQ), Code::KeyQ);
+        assert_eq!(KeyCode::try_from(Code::KeyQ), Ok(KeyCode::KeyQ));
+        let key = winit_keyboard::Key::Character("q".into());
+        assert_eq!(Key::from(key.clone()), Key::Character("q".to_string()));
+        assert_eq!(
+            winit_keyboard::Key::from(Key::Character("q".to_string())),
+            key
+        );
+    }
+
+    #[test]
+    fn named_key() {
+        let key = winit_keyboard::Key::Named(winit_keyboard::NamedKey::ArrowLeft);
+        assert_eq!(Key::from(key.clone()), Key::Named(NamedKey::ArrowLeft));
+        assert_eq!(
+            winit_keyboard::Key::from(Key::Named(NamedKey::ArrowLeft)),
+            key
+        );
+
+        let space = winit_keyboard::Key::Named(winit_keyboard::NamedKey::Space);
+        assert_eq!(Key::from(space.clone()), Key::Character(" ".to_string()));
+        assert_eq!(
+            winit_keyboard::Key::from(Key::Character(" ".to_string())),
+            space
+        );
+    }
+
+    #[test]
+    fn code() {
+        assert_eq!(Code::from(KeyCode::SuperLeft), Code::MetaLeft);
+        assert_eq!(KeyCode::try_from(Code::MetaLeft), Ok(KeyCode::SuperLeft));
+        assert_eq!(Code::from(PhysicalKey::Code(KeyCode::F13)), Code::F13);
+        assert_eq!(
+            KeyCode::try_from(Code::Unidentified),
+            Err(NoEquivalentError)
+        );
+    }
+}
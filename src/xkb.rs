@@ -0,0 +1,43 @@
+//! Conversions from and to xkb key codes.
+//!
+//! xkbcommon and X11 use the Linux evdev key codes offset by 8.
+
+use crate::Code;
+
+/// Offset between evdev and xkb key codes.
+const XKB_OFFSET: u32 = 8;
+
+impl Code {
+    /// Get the code for an xkb key code, as used by xkbcommon and X11.
+    ///
+    /// The xkb key code is the evdev key code plus 8, see
+    /// [`Code::from_evdev_keycode`]. Returns `None` if the key code has
+    /// no corresponding [`Code`].
+    pub fn from_xkb_keycode(keycode: u32) -> Option<Code> {
+        let evdev = keycode.checked_sub(XKB_OFFSET)?;
+        Code::from_evdev_keycode(u16::try_from(evdev).ok()?)
+    }
+
+    /// Get the xkb key code for a code, as used by xkbcommon and X11.
+    ///
+    /// The xkb key code is the evdev key code plus 8, see
+    /// [`Code::to_evdev_keycode`]. Note that X11 only supports key codes
+    /// up to 255, while xkbcommon has no such limit.
+    pub fn to_xkb_keycode(self) -> Option<u32> {
+        self.to_evdev_keycode()
+            .map(|keycode| u32::from(keycode) + XKB_OFFSET)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn xkb_keycode() {
+        assert_eq!(Code::KeyA.to_xkb_keycode(), Some(38));
+        assert_eq!(Code::from_xkb_keycode(38), Some(Code::KeyA));
+        assert_eq!(Code::from_xkb_keycode(7), None);
+        assert_eq!(Code::Unidentified.to_xkb_keycode(), None);
+    }
+}